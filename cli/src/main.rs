@@ -1,67 +1,559 @@
-use std::{io::BufWriter, path::PathBuf, sync::Arc, time::Instant};
+use std::{
+    io::BufWriter,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
 
-use anyhow::Result;
-use clap::Parser;
-use cliclack::{intro, note, outro, progress_bar, spinner};
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use cliclack::{intro, log, note, outro, progress_bar, spinner, ProgressBar};
 use seed_maker::{
     sdv::{self, GameData, Locale},
-    Progress, SeedFinder, SeedFinderConfig,
+    CancelHandle, ConfigFormat, Explanation, Interval, Progress, SeedFinder, SeedFinderConfig,
 };
 
+mod output;
+
+use output::{write_statistics, Output, OutputFormat, StatisticsFormat};
+
 /// Stardew Valley seed finder.
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
-    config_file: PathBuf,
+    #[command(subcommand)]
+    command: Command,
 }
 
-fn main() -> Result<()> {
-    let args = Args::parse();
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Search for seeds matching a config.
+    Search {
+        config_file: PathBuf,
+
+        /// Periodically save search progress to this file.
+        #[arg(long)]
+        checkpoint: Option<PathBuf>,
+
+        /// Resume the search from the file given by `--checkpoint`.
+        #[arg(long, requires = "checkpoint")]
+        resume: bool,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Print reports for specific seeds without searching.
+    #[command(allow_negative_numbers = true)]
+    Report {
+        config_file: PathBuf,
+
+        /// Seeds to report on.
+        #[arg(required = true)]
+        seeds: Vec<i32>,
+
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+
+    /// Check whether each predictor in a config matches a seed.
+    ///
+    /// Exits with a non-zero status if any predictor doesn't match.
+    #[command(allow_negative_numbers = true)]
+    Verify { config_file: PathBuf, seed: i32 },
+
+    /// Explain why each predictor in a config does or doesn't match a seed.
+    #[command(allow_negative_numbers = true)]
+    Explain { config_file: PathBuf, seed: i32 },
+
+    /// Estimate how many seeds match a config and how long a search will
+    /// take by sampling seeds.
+    Estimate {
+        config_file: PathBuf,
+
+        /// Number of seeds to sample.
+        #[arg(long, default_value_t = 10000)]
+        samples: usize,
+    },
+
+    /// Tally histograms of each predictor's outcomes across the seed range.
+    ///
+    /// Press Ctrl-C to stop early and write statistics for the seeds scanned
+    /// so far.
+    Statistics {
+        config_file: PathBuf,
+
+        /// Format of the statistics.
+        #[arg(long, value_enum, default_value_t = StatisticsFormat::Table)]
+        format: StatisticsFormat,
+
+        /// Write statistics to this file instead of stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Print a JSON Schema for config files.
+    ///
+    /// Reference it from a config's `$schema` field to get autocomplete and
+    /// validation in editors.
+    Schema {
+        /// Write the schema to this file instead of stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Check that a config can be loaded without running a search.
+    ///
+    /// Lists every problem found and exits with a non-zero status if the
+    /// config is invalid.
+    Validate { config_file: PathBuf },
+
+    /// Rewrite a config in another format.
+    ///
+    /// Formats are picked by file extension: `.json`, `.toml`, `.yaml`,
+    /// `.yml` or `.ron`.
+    Convert { input: PathBuf, output: PathBuf },
+}
+
+#[derive(clap::Args, Debug)]
+struct OutputArgs {
+    /// Format of the found seeds and their reports.
+    ///
+    /// Only `pretty` shows progress.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    output_format: OutputFormat,
+
+    /// Write found seeds and their reports to this file instead of stdout.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+fn note_report(
+    finder: &SeedFinder,
+    game_data: &GameData,
+    locale: &Locale,
+    seed: i32,
+) -> Result<()> {
+    let mut buf = BufWriter::new(Vec::new());
+    finder.report(game_data, locale, seed, &mut buf)?;
+    let buf = buf.into_inner()?;
+    let report = String::from_utf8_lossy(&buf);
+
+    note(format!("{seed}"), report)?;
+    Ok(())
+}
 
-    intro("Seed Maker")?;
+fn start_progress_bar(finder: &SeedFinder, message: &str, position: u64) -> ProgressBar {
+    let pb = progress_bar(finder.seed_count());
+    pb.start(message);
+    pb.inc(position);
+    pb
+}
+
+fn load_game_data(interactive: bool) -> Result<(GameData, Locale)> {
+    let spinner = spinner();
+    if interactive {
+        spinner.start("Loading game data...");
+    }
+    let content_path = sdv::gamedata::get_game_content_path().context("can't find game content")?;
+    let game_data = GameData::from_content_dir(content_path.clone())?;
+    let locale = Locale::from_content_dir(content_path, "en-EN")?;
+    if interactive {
+        spinner.stop("Game data loaded.");
+    }
+    Ok((game_data, locale))
+}
 
+/// Calibrate `finder` and log the resulting predictor order.
+///
+/// Non-interactive runs log to stderr so the order is still visible without
+/// mixing it into the output.
+fn log_calibration(
+    finder: &SeedFinder,
+    config: &SeedFinderConfig,
+    interactive: bool,
+) -> Result<()> {
     let spinner = spinner();
-    spinner.start("Loading game data...");
-    let game_data =
-        GameData::from_content_dir(sdv::gamedata::get_game_content_path().unwrap()).unwrap();
-    let locale =
-        Locale::from_content_dir(sdv::gamedata::get_game_content_path().unwrap(), "en-EN")?;
-    spinner.stop("Game data loaded.");
-
-    let config_data = std::fs::read_to_string(args.config_file).unwrap();
-    let config: SeedFinderConfig = serde_json::from_str(&config_data).unwrap();
-    let finder = Arc::new(SeedFinder::new(&game_data, &config).unwrap());
-
-    let pb = progress_bar(i32::MAX as u64);
-    pb.start("Finding seeds...");
+    if interactive {
+        spinner.start("Calibrating predictors...");
+    }
+    let calibration = finder.calibrate()?;
+    let calibrated = format!("Calibrated predictors on {} seeds.", calibration.samples);
+    if interactive {
+        spinner.stop(calibrated);
+    } else {
+        eprintln!("{calibrated}");
+    }
+
+    let order = calibration
+        .order
+        .iter()
+        .map(|&i| {
+            let name: &str = (&config.predictors[i]).into();
+            let stats = &calibration.stats[i];
+            format!(
+                "predictors[{i}] ({name}): {:.2?} per seed, {:.1}% pass",
+                stats.cost,
+                stats.pass_rate * 100.0
+            )
+        })
+        .collect::<Vec<_>>();
+    let order = format!("Predictor order:\n{}", order.join("\n"));
+    if interactive {
+        log::info(order)?;
+    } else {
+        eprintln!("{order}");
+    }
+    Ok(())
+}
+
+fn search(
+    config_file: &Path,
+    checkpoint: Option<&Path>,
+    resume: bool,
+    output_args: &OutputArgs,
+) -> Result<()> {
+    let interactive = output_args.output_format == OutputFormat::Pretty;
+
+    if interactive {
+        intro("Seed Maker")?;
+    }
+    let (game_data, locale) = load_game_data(interactive)?;
+
+    let config = SeedFinderConfig::from_path(config_file)?;
+    let mut finder = SeedFinder::new(&game_data, &locale, &config)?;
+    if let Some(checkpoint) = checkpoint {
+        if resume {
+            finder.resume_from(checkpoint)?;
+        }
+        finder.checkpoint_to(checkpoint);
+    }
+    if config.calibrate && !config.ranked {
+        log_calibration(&finder, &config, interactive)?;
+    }
+    let finder = Arc::new(finder);
+    let mut output = Output::new(output_args.output_format, output_args.output.as_deref())?;
+
+    let mut pb = interactive.then(|| start_progress_bar(&finder, "Finding seeds...", 0));
     let start = Instant::now();
-    let progress = SeedFinder::find_seeds_async(finder.clone(), 1000);
+    let (progress, cancel) = SeedFinder::find_seeds_async(finder.clone(), 1000);
+    ctrlc::set_handler(move || cancel.cancel())?;
     let mut last_progress = 0;
-    let seeds = loop {
-        match progress.recv().unwrap() {
+    let (seeds, cancelled) = loop {
+        match progress.recv().context("search stopped unexpectedly")? {
             Progress::Progress(seeds_processed) => {
-                pb.inc((seeds_processed as u64) - last_progress);
+                if let Some(pb) = &pb {
+                    pb.inc((seeds_processed as u64) - last_progress);
+                }
                 last_progress = seeds_processed as u64;
             }
-            Progress::Complete(seeds) => break seeds,
+            Progress::Found(seed) => {
+                output.seed(&finder, &game_data, &locale, seed)?;
+                if let Some(pb) = &mut pb {
+                    // Print the report between progress bars so it isn't
+                    // clobbered by progress updates.
+                    pb.stop(format!("Found seed {seed}"));
+                    note_report(&finder, &game_data, &locale, seed)?;
+                    *pb = start_progress_bar(&finder, "Finding seeds...", last_progress);
+                }
+            }
+            Progress::Ranked(seed, score) => {
+                output.seed(&finder, &game_data, &locale, seed)?;
+                if let Some(pb) = &mut pb {
+                    pb.stop(format!("Ranked seed {seed} (score {score:.3})"));
+                    note_report(&finder, &game_data, &locale, seed)?;
+                    *pb = start_progress_bar(&finder, "Finding seeds...", last_progress);
+                }
+            }
+            Progress::Complete(seeds) => break (seeds, false),
+            Progress::Cancelled(seeds) => break (seeds, true),
+            Progress::Error(e) => {
+                if let Some(pb) = &pb {
+                    pb.error("Seed finding failed");
+                }
+                return Err(e);
+            }
         }
     };
     let elapsed = start.elapsed();
-    pb.stop("Seed finding done");
-    for seed in &seeds {
-        let mut buf = BufWriter::new(Vec::new());
-        finder.report(&game_data, &locale, *seed, &mut buf)?;
-        //let buf = buf.into_inner()?;
-        let buf = buf.into_inner()?;
-        let report = String::from_utf8_lossy(&buf);
+    output.finish()?;
 
-        note(format!("{seed}"), report)?;
+    let Some(pb) = pb else {
+        return Ok(());
+    };
+    let results = if config.ranked {
+        "seeds ranked"
+    } else {
+        "seeds found"
+    };
+    if cancelled {
+        pb.cancel("Seed finding cancelled");
+        if let Some(checkpoint) = checkpoint {
+            outro(format!(
+                "{} {results}. Resume with --checkpoint {} --resume",
+                seeds.len(),
+                checkpoint.display()
+            ))?;
+        } else {
+            outro(format!("{} {results}.", seeds.len()))?;
+        }
+        return Ok(());
     }
+    pb.stop("Seed finding done");
 
     outro(format!(
-        "Finished: {} seeds found in {}s.",
+        "Finished: {} {results} in {}s.",
         seeds.len(),
         elapsed.as_secs_f32()
     ))?;
     Ok(())
 }
+
+fn report(config_file: &Path, seeds: &[i32], output_args: &OutputArgs) -> Result<()> {
+    let interactive = output_args.output_format == OutputFormat::Pretty;
+    let (game_data, locale) = load_game_data(false)?;
+    let config = SeedFinderConfig::from_path(config_file)?;
+    let finder = SeedFinder::new(&game_data, &locale, &config)?;
+
+    let mut output = Output::new(output_args.output_format, output_args.output.as_deref())?;
+    for &seed in seeds {
+        output.seed(&finder, &game_data, &locale, seed)?;
+        if interactive {
+            note_report(&finder, &game_data, &locale, seed)?;
+        }
+    }
+    output.finish()
+}
+
+fn verify(config_file: &Path, seed: i32) -> Result<()> {
+    let (game_data, locale) = load_game_data(false)?;
+    let config = SeedFinderConfig::from_path(config_file)?;
+    let finder = SeedFinder::new(&game_data, &locale, &config)?;
+
+    let results = finder.verify(seed)?;
+    for (i, (config, matched)) in config.predictors.iter().zip(&results).enumerate() {
+        let name: &str = config.into();
+        let status = if *matched { "pass" } else { "fail" };
+        println!("predictors[{i}] ({name}): {status}");
+    }
+
+    if results.iter().all(|matched| *matched) {
+        println!("Seed {seed}: pass");
+        Ok(())
+    } else {
+        println!("Seed {seed}: fail");
+        std::process::exit(1);
+    }
+}
+
+fn print_explanation(explanation: &Explanation, depth: usize) {
+    let indent = "  ".repeat(depth);
+    if let Some(detail) = &explanation.detail {
+        println!("{indent}{detail}");
+    }
+    for child in &explanation.children {
+        let status = if child.matched { "pass" } else { "fail" };
+        println!("{indent}- {status}");
+        print_explanation(child, depth + 1);
+    }
+}
+
+fn explain(config_file: &Path, seed: i32) -> Result<()> {
+    let (game_data, locale) = load_game_data(false)?;
+    let config = SeedFinderConfig::from_path(config_file)?;
+    let finder = SeedFinder::new(&game_data, &locale, &config)?;
+
+    let explanations = finder.explain(&game_data, &locale, seed)?;
+    for (i, (config, explanation)) in config.predictors.iter().zip(&explanations).enumerate() {
+        let name: &str = config.into();
+        let status = if explanation.matched { "pass" } else { "fail" };
+        println!("predictors[{i}] ({name}): {status}");
+        print_explanation(explanation, 1);
+    }
+
+    let matched = explanations.iter().all(|explanation| explanation.matched);
+    println!("Seed {seed}: {}", if matched { "pass" } else { "fail" });
+    Ok(())
+}
+
+fn format_interval(interval: &Interval, format: impl Fn(f64) -> String) -> String {
+    format!(
+        "{} (95% CI {} - {})",
+        format(interval.estimate),
+        format(interval.low),
+        format(interval.high)
+    )
+}
+
+fn percent(value: f64) -> String {
+    format!("{:.3}%", value * 100.0)
+}
+
+fn estimate(config_file: &Path, samples: usize) -> Result<()> {
+    let (game_data, locale) = load_game_data(false)?;
+    let config = SeedFinderConfig::from_path(config_file)?;
+    let finder = SeedFinder::new(&game_data, &locale, &config)?;
+
+    let estimate = finder.estimate(samples)?;
+    println!(
+        "Sampled {} of {} seeds: {} matched",
+        estimate.samples,
+        finder.seed_count(),
+        estimate.sample_matches
+    );
+    for (i, (config, predictor)) in config
+        .predictors
+        .iter()
+        .zip(&estimate.predictors)
+        .enumerate()
+    {
+        let name: &str = config.into();
+        println!(
+            "predictors[{i}] ({name}): {} pass, {:.2?} per seed",
+            format_interval(&predictor.pass_rate, percent),
+            predictor.cost
+        );
+    }
+    println!(
+        "Match rate: {}",
+        format_interval(&estimate.match_rate, percent)
+    );
+    println!(
+        "Estimated matches: {}",
+        format_interval(&estimate.total_matches, |value| format!("{value:.0}"))
+    );
+    println!(
+        "Projected search time: {}",
+        format_interval(&estimate.search_seconds, |value| {
+            format!("{:.1?}", Duration::from_secs_f64(value))
+        })
+    );
+    Ok(())
+}
+
+fn statistics(config_file: &Path, format: StatisticsFormat, output: Option<&Path>) -> Result<()> {
+    let (game_data, locale) = load_game_data(false)?;
+    let config = SeedFinderConfig::from_path(config_file)?;
+    let finder = SeedFinder::new(&game_data, &locale, &config)?;
+
+    let cancel = CancelHandle::default();
+    ctrlc::set_handler({
+        let cancel = cancel.clone();
+        move || cancel.cancel()
+    })?;
+
+    let pb = start_progress_bar(&finder, "Scanning seeds...", 0);
+    let seeds_scanned = AtomicU64::new(0);
+    let statistics = thread::scope(|scope| {
+        let scan = scope.spawn(|| {
+            finder.statistics(&game_data, &locale, &cancel, |seeds| {
+                seeds_scanned.fetch_max(seeds, Ordering::Relaxed);
+            })
+        });
+        let mut last_progress = 0;
+        while !scan.is_finished() {
+            thread::sleep(Duration::from_millis(100));
+            let progress = seeds_scanned.load(Ordering::Relaxed);
+            pb.inc(progress - last_progress);
+            last_progress = progress;
+        }
+        scan.join().expect("statistics scan panicked")
+    });
+    let statistics = match statistics {
+        Ok(statistics) => statistics,
+        Err(e) => {
+            pb.error("Statistics failed");
+            return Err(e);
+        }
+    };
+    if cancel.is_cancelled() {
+        pb.cancel("Statistics cancelled");
+    } else {
+        pb.stop("Statistics done");
+    }
+
+    let names = config
+        .predictors
+        .iter()
+        .map(|config| config.into())
+        .collect::<Vec<&str>>();
+    write_statistics(format, output, &names, &statistics)
+}
+
+fn schema(output: Option<&Path>) -> Result<()> {
+    let schema = serde_json::to_string_pretty(&seed_maker::config_schema())?;
+    match output {
+        Some(path) => std::fs::write(path, schema + "\n")
+            .with_context(|| format!("can't write schema {}", path.display()))?,
+        None => println!("{schema}"),
+    }
+    Ok(())
+}
+
+fn convert(input: &Path, output: &Path) -> Result<()> {
+    let config = SeedFinderConfig::from_path(input)?;
+    let format = ConfigFormat::from_path(output)?;
+    let data = format.serialize(&config)?;
+    std::fs::write(output, data)
+        .with_context(|| format!("can't write config {}", output.display()))?;
+    Ok(())
+}
+
+fn validate(config_file: &Path) -> Result<()> {
+    let (game_data, locale) = load_game_data(false)?;
+    let config = SeedFinderConfig::from_path(config_file)?;
+
+    let problems = config.validate(&game_data, &locale);
+    for problem in &problems {
+        println!("{}: {problem}", config_file.display());
+    }
+    let errors = problems.iter().filter(|problem| !problem.warning).count();
+    if errors > 0 {
+        println!("{}: {errors} problems found", config_file.display());
+        std::process::exit(1);
+    }
+
+    SeedFinder::new(&game_data, &locale, &config)?;
+    println!(
+        "{}: valid config with {} predictors",
+        config_file.display(),
+        config.predictors.len()
+    );
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    match &args.command {
+        Command::Search {
+            config_file,
+            checkpoint,
+            resume,
+            output,
+        } => search(config_file, checkpoint.as_deref(), *resume, output),
+        Command::Report {
+            config_file,
+            seeds,
+            output,
+        } => report(config_file, seeds, output),
+        Command::Verify { config_file, seed } => verify(config_file, *seed),
+        Command::Explain { config_file, seed } => explain(config_file, *seed),
+        Command::Estimate {
+            config_file,
+            samples,
+        } => estimate(config_file, *samples),
+        Command::Statistics {
+            config_file,
+            format,
+            output,
+        } => statistics(config_file, *format, output.as_deref()),
+        Command::Schema { output } => schema(output.as_deref()),
+        Command::Validate { config_file } => validate(config_file),
+        Command::Convert { input, output } => convert(input, output),
+    }
+}
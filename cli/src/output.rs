@@ -0,0 +1,190 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use seed_maker::{
+    sdv::{GameData, Locale},
+    SeedFinder, Statistics,
+};
+use serde_json::Value;
+
+/// Format used to write found seeds and their reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Interactive output with progress bars.
+    Pretty,
+
+    /// Plain text reports.
+    Plain,
+
+    /// One structured JSON report per line (JSON Lines).
+    Json,
+
+    /// CSV with one `seed,field,value` row per field of each structured
+    /// report.
+    ///
+    /// `field` is the path of the field in the JSON report, like
+    /// `predictors[0].items[1].quantity`.
+    Csv,
+}
+
+/// Format used to write [`Statistics`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StatisticsFormat {
+    /// A plain text table per predictor.
+    Table,
+
+    /// A single JSON object.
+    Json,
+}
+
+/// Write `statistics` to `path` or stdout if `path` is `None`.
+///
+/// `names` are the names of the predictors in configuration order.
+pub fn write_statistics(
+    format: StatisticsFormat,
+    path: Option<&Path>,
+    names: &[&str],
+    statistics: &Statistics,
+) -> Result<()> {
+    let mut writer: Box<dyn Write> = match path {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(io::stdout()),
+    };
+    match format {
+        StatisticsFormat::Table => {
+            writeln!(
+                writer,
+                "Scanned {} seeds, {} matched all predictors",
+                statistics.seeds_scanned, statistics.matches
+            )?;
+            for (i, (name, histogram)) in names.iter().zip(&statistics.predictors).enumerate() {
+                writeln!(writer)?;
+                writeln!(writer, "predictors[{i}] ({name}):")?;
+                writeln!(writer, "  {:>12}  {:>8}  outcome", "count", "percent")?;
+                for bucket in &histogram.buckets {
+                    let percent = bucket.count as f64 / statistics.seeds_scanned as f64 * 100.0;
+                    writeln!(
+                        writer,
+                        "  {:>12}  {:>7.3}%  {}",
+                        bucket.count, percent, bucket.outcome
+                    )?;
+                }
+            }
+        }
+        StatisticsFormat::Json => {
+            serde_json::to_writer_pretty(&mut writer, statistics)?;
+            writeln!(writer)?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes found seeds and their reports in an [`OutputFormat`].
+pub struct Output {
+    format: OutputFormat,
+    writer: Box<dyn Write>,
+}
+
+impl Output {
+    /// Create a new `Output` writing to `path` or stdout if `path` is `None`.
+    ///
+    /// [`OutputFormat::Pretty`] results are written to `path` as plain text.
+    pub fn new(format: OutputFormat, path: Option<&Path>) -> Result<Self> {
+        let writer: Box<dyn Write> = match path {
+            Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+            None if format == OutputFormat::Pretty => Box::new(io::sink()),
+            None => Box::new(io::stdout()),
+        };
+        let mut output = Self { format, writer };
+        if format == OutputFormat::Csv {
+            writeln!(output.writer, "seed,field,value")?;
+        }
+        Ok(output)
+    }
+
+    /// Write `seed` and its report.
+    pub fn seed(
+        &mut self,
+        finder: &SeedFinder,
+        game_data: &GameData,
+        locale: &Locale,
+        seed: i32,
+    ) -> Result<()> {
+        match self.format {
+            OutputFormat::Pretty | OutputFormat::Plain => {
+                writeln!(self.writer, "Seed: {seed}")?;
+                finder.report(game_data, locale, seed, &mut self.writer)?;
+                writeln!(self.writer)?;
+            }
+            OutputFormat::Json => {
+                // Each report is written as soon as it's found so nothing is
+                // lost if the search fails or is cancelled.
+                let report = finder.report_structured(game_data, locale, seed)?;
+                serde_json::to_writer(&mut self.writer, &report)?;
+                writeln!(self.writer)?;
+            }
+            OutputFormat::Csv => {
+                let report = finder.report_structured(game_data, locale, seed)?;
+                let mut fields = Vec::new();
+                flatten_fields(
+                    "predictors",
+                    &serde_json::to_value(&report.predictors)?,
+                    &mut fields,
+                );
+                for (field, value) in fields {
+                    writeln!(
+                        self.writer,
+                        "{seed},{},{}",
+                        csv_field(&field),
+                        csv_field(&value)
+                    )?;
+                }
+            }
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Finish writing output.
+    pub fn finish(mut self) -> Result<()> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Collect the path and value of every scalar in `value`, found at `path`,
+/// into `fields`.
+///
+/// Nulls are written as empty values.
+fn flatten_fields(path: &str, value: &Value, fields: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                flatten_fields(&format!("{path}.{key}"), value, fields);
+            }
+        }
+        Value::Array(array) => {
+            for (i, value) in array.iter().enumerate() {
+                flatten_fields(&format!("{path}[{i}]"), value, fields);
+            }
+        }
+        Value::String(string) => fields.push((path.to_string(), string.clone())),
+        Value::Null => fields.push((path.to_string(), String::new())),
+        Value::Bool(_) | Value::Number(_) => fields.push((path.to_string(), value.to_string())),
+    }
+}
+
+/// Quote `value` for CSV if it contains a separator, quote or newline.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use rayon::prelude::*;
+use sdv::predictor::PredictionGameState;
+
+use crate::{Predictor, Result};
+
+/// Number of seeds sampled when calibrating.
+const CALIBRATION_SAMPLES: i64 = 4096;
+
+/// Measured cost and pass rate of a single predictor.
+#[derive(Clone, Debug)]
+pub struct PredictorStats {
+    /// Average time taken to check one seed.
+    pub cost: Duration,
+
+    /// Fraction of sampled seeds that matched, between 0.0 and 1.0.
+    pub pass_rate: f64,
+}
+
+impl PredictorStats {
+    /// Expected time spent on a seed per seed this predictor rejects.
+    ///
+    /// Running predictors in ascending rank order minimizes the expected cost
+    /// of checking a seed.
+    fn rank(&self) -> f64 {
+        if self.pass_rate >= 1.0 {
+            return f64::INFINITY;
+        }
+        self.cost.as_secs_f64() / (1.0 - self.pass_rate)
+    }
+}
+
+/// Results of sampling a [`SeedFinder`](crate::SeedFinder)'s predictors.
+///
+/// Produced by [`SeedFinder::calibrate`](crate::SeedFinder::calibrate).
+#[derive(Clone, Debug)]
+pub struct Calibration {
+    /// Number of seeds sampled.
+    pub samples: usize,
+
+    /// Stats for each predictor, in configuration order.
+    pub stats: Vec<PredictorStats>,
+
+    /// Configuration indices of the predictors in the order they are
+    /// evaluated while searching.
+    pub order: Vec<usize>,
+}
+
+impl Calibration {
+    /// Measure `predictors` against seeds sampled evenly from `seed_range`.
+    ///
+    /// Every predictor is run against every sample so pass rates are
+    /// independent of the current order.
+    pub(crate) fn measure(
+        predictors: &[Box<dyn Predictor>],
+        initial_state: &PredictionGameState,
+        seed_range: &RangeInclusive<i32>,
+    ) -> Result<Self> {
+        let start = *seed_range.start() as i64;
+        let count = *seed_range.end() as i64 - start + 1;
+        let samples = count.min(CALIBRATION_SAMPLES);
+
+        let totals = (0..samples)
+            .into_par_iter()
+            .map(|sample| {
+                let seed = (start + sample * count / samples) as i32;
+                let state = PredictionGameState {
+                    game_id: seed as u32,
+                    ..*initial_state
+                };
+                predictors
+                    .iter()
+                    .enumerate()
+                    .map(|(i, predictor)| {
+                        let started = Instant::now();
+                        let matched = predictor
+                            .predict(&state)
+                            .with_context(|| format!("predictors[{i}] failed on seed {seed}"))?;
+                        Ok((started.elapsed(), matched as usize))
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .try_reduce(
+                || vec![(Duration::ZERO, 0); predictors.len()],
+                |a, b| {
+                    Ok(a.into_iter()
+                        .zip(b)
+                        .map(|(a, b)| (a.0 + b.0, a.1 + b.1))
+                        .collect())
+                },
+            )?;
+
+        let stats = totals
+            .into_iter()
+            .map(|(elapsed, passed)| PredictorStats {
+                cost: elapsed / samples as u32,
+                pass_rate: passed as f64 / samples as f64,
+            })
+            .collect::<Vec<_>>();
+
+        // Sorting is stable so predictors with equal rank keep their
+        // configuration order.
+        let mut order = (0..stats.len()).collect::<Vec<_>>();
+        order.sort_by(|a, b| stats[*a].rank().total_cmp(&stats[*b].rank()));
+
+        Ok(Self {
+            samples: samples as usize,
+            stats,
+            order,
+        })
+    }
+}
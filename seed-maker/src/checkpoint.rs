@@ -0,0 +1,171 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{PredictorConfig, Result, RngType, SeedFinderConfig, SeedFinderStateConfig};
+
+/// Saved state of a partially completed search.
+///
+/// Written periodically by a [`SeedFinder`](crate::SeedFinder) with
+/// checkpointing enabled and used to resume the search later.
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "config_hash": 5210930197465813446,
+///    "next_seed": 104857600,
+///    "seeds": [
+///        1180032,
+///        38215449
+///    ]
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Checkpoint {
+    /// Hash of the [`SeedFinderConfig`] used for the search.
+    pub config_hash: u64,
+
+    /// All seeds before this one have been searched.
+    pub next_seed: i64,
+
+    /// Matching seeds found so far, in ascending order.
+    ///
+    /// Holds every match before `next_seed` so it may contain more than
+    /// `max_seeds` seeds.
+    pub seeds: Vec<i32>,
+}
+
+impl Checkpoint {
+    /// Load a checkpoint from `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path)
+            .with_context(|| format!("can't read checkpoint {}", path.display()))?;
+        let checkpoint = serde_json::from_str(&data)
+            .with_context(|| format!("can't parse checkpoint {}", path.display()))?;
+        Ok(checkpoint)
+    }
+
+    /// Save a checkpoint to `path`.
+    ///
+    /// The checkpoint is written to a temporary file first and then renamed so
+    /// an interrupted write never leaves a corrupt checkpoint behind.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("can't write checkpoint {}", tmp_path.display()))?;
+        fs::rename(&tmp_path, path)
+            .with_context(|| format!("can't write checkpoint {}", path.display()))?;
+        Ok(())
+    }
+}
+
+/// Fields of a [`SeedFinderConfig`] that decide which seeds match.
+///
+/// Search options like `max_seeds` or `calibrate` are left out so changing
+/// them doesn't invalidate a checkpoint.
+#[derive(Serialize)]
+struct MatchingConfig<'a> {
+    rng_type: &'a RngType,
+    seed_start: i32,
+    seed_end: i32,
+    game_state: &'a SeedFinderStateConfig,
+    predictors: &'a [PredictorConfig],
+}
+
+/// Hash a [`SeedFinderConfig`] to detect changes between a checkpoint and
+/// resuming from it.
+///
+/// Only the fields that decide which seeds match are hashed: the RNG type,
+/// the seed range, the game state and the predictors.  Uses 64 bit FNV-1a
+/// over their JSON serialization so the hash is stable across builds and
+/// platforms.
+pub(crate) fn config_hash(config: &SeedFinderConfig) -> Result<u64> {
+    let (seed_start, seed_end) = if config.full_range {
+        (i32::MIN, i32::MAX)
+    } else {
+        (config.seed_start, config.seed_end)
+    };
+    let data = serde_json::to_string(&MatchingConfig {
+        rng_type: &config.rng_type,
+        seed_start,
+        seed_end,
+        game_state: &config.game_state,
+        predictors: &config.predictors,
+    })?;
+    Ok(data.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SeedFinderConfig {
+        serde_json::from_str(r#"{"max_seeds": 10, "game_state": {"day": 1}, "predictors": []}"#)
+            .unwrap()
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path =
+            std::env::temp_dir().join(format!("seed-maker-checkpoint-{}.json", std::process::id()));
+        let checkpoint = Checkpoint {
+            config_hash: 5210930197465813446,
+            next_seed: i32::MAX as i64 + 1,
+            seeds: vec![i32::MIN, -1, 0, 38215449],
+        };
+        checkpoint.save(&path).unwrap();
+        let loaded = Checkpoint::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.config_hash, checkpoint.config_hash);
+        assert_eq!(loaded.next_seed, checkpoint.next_seed);
+        assert_eq!(loaded.seeds, checkpoint.seeds);
+    }
+
+    #[test]
+    fn hash_ignores_search_options() {
+        let hash = config_hash(&config()).unwrap();
+        let mut changed = config();
+        changed.max_seeds = 20;
+        changed.deterministic = true;
+        changed.calibrate = false;
+        assert_eq!(config_hash(&changed).unwrap(), hash);
+    }
+
+    #[test]
+    fn hash_covers_matching_fields() {
+        let hash = config_hash(&config()).unwrap();
+        let changes: [fn(&mut SeedFinderConfig); 6] = [
+            |config| config.rng_type = RngType::Legacy,
+            |config| config.seed_start = 1,
+            |config| config.seed_end = 1000,
+            |config| config.full_range = true,
+            |config| config.game_state.geodes_cracked = 2,
+            |config| {
+                config.predictors =
+                    vec![serde_json::from_str(r#"{"type": "weather", "is_rain": true}"#).unwrap()]
+            },
+        ];
+        for (i, change) in changes.iter().enumerate() {
+            let mut changed = config();
+            change(&mut changed);
+            assert_ne!(config_hash(&changed).unwrap(), hash, "change {i}");
+        }
+
+        // The full range hashes the same as spelling it out.
+        let mut full_range = config();
+        full_range.full_range = true;
+        let mut spelled_out = config();
+        spelled_out.seed_start = i32::MIN;
+        spelled_out.seed_end = i32::MAX;
+        assert_eq!(
+            config_hash(&full_range).unwrap(),
+            config_hash(&spelled_out).unwrap()
+        );
+    }
+}
@@ -0,0 +1,437 @@
+use std::{fmt::Debug, io::Write, marker::PhantomData};
+
+use schemars::JsonSchema;
+use sdv::{predictor::PredictionGameState, rng::SeedGenerator, GameData, Locale};
+use serde::{Deserialize, Serialize};
+
+use crate::{Explanation, Predictor, PredictorConfig, PredictorReport, Result};
+
+/// Configuration for the [`AnyOf`] Predictor.
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "type": "any_of",
+///    "predictors": [
+///        {
+///            "type": "weather",
+///            "is_rain": true
+///        },
+///        {
+///            "type": "night_event",
+///            "event": "fairy"
+///        }
+///    ]
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct AnyOfConfig {
+    /// [`PredictorConfig`]s of the child predictors.
+    pub predictors: Vec<PredictorConfig>,
+}
+
+/// Configuration for the [`AllOf`] Predictor.
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "type": "all_of",
+///    "predictors": [
+///        {
+///            "type": "weather",
+///            "is_rain": true
+///        },
+///        {
+///            "type": "night_event",
+///            "event": "fairy"
+///        }
+///    ]
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct AllOfConfig {
+    /// [`PredictorConfig`]s of the child predictors.
+    pub predictors: Vec<PredictorConfig>,
+}
+
+/// Configuration for the [`Not`] Predictor.
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "type": "not",
+///    "child": {
+///        "type": "weather",
+///        "is_rain": true
+///    }
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct NotConfig {
+    /// [`PredictorConfig`] of the child predictor to invert.
+    pub child: Box<PredictorConfig>,
+}
+
+/// Structured report of an [`AnyOf`] or [`AllOf`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BranchesReport {
+    /// Reports of each child predictor in configuration order.
+    pub branches: Vec<BranchReport>,
+}
+
+/// Structured report of a single child predictor of an [`AnyOf`] or
+/// [`AllOf`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BranchReport {
+    /// True if the child predictor matched.
+    pub matched: bool,
+
+    /// Report of the child predictor.
+    pub report: PredictorReport,
+}
+
+/// Structured report of a [`Not`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NotReport {
+    /// True if the child predictor matched.
+    pub child_matched: bool,
+
+    /// Report of the child predictor.
+    pub child: Box<PredictorReport>,
+}
+
+fn children<G: 'static + Send + Sync + SeedGenerator>(
+    game_data: &GameData,
+    locale: &Locale,
+    configs: &[PredictorConfig],
+) -> Result<Vec<Box<dyn Predictor>>> {
+    configs
+        .iter()
+        .map(|config| config.predictor::<G>(game_data, locale))
+        .collect()
+}
+
+/// Write the report of `predictor` after a label already written to `writer`.
+///
+/// Children that write nothing still end the label's line so the next line
+/// of output doesn't run on from it.
+fn report_child(
+    predictor: &dyn Predictor,
+    game_data: &GameData,
+    locale: &Locale,
+    state: &PredictionGameState,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let mut report = Vec::new();
+    predictor.report(game_data, locale, state, &mut report)?;
+    if !report.ends_with(b"\n") {
+        report.push(b'\n');
+    }
+    writer.write_all(&report)?;
+    Ok(())
+}
+
+fn report_branches(
+    name: &str,
+    predictors: &[Box<dyn Predictor>],
+    game_data: &GameData,
+    locale: &Locale,
+    state: &PredictionGameState,
+    writer: &mut dyn Write,
+) -> Result<()> {
+    let matches = predictors
+        .iter()
+        .map(|predictor| predictor.predict(state))
+        .collect::<Result<Vec<_>>>()?;
+    let num_matches = matches.iter().filter(|matched| **matched).count();
+    writeln!(
+        writer,
+        "{name} ({num_matches}/{} matched):",
+        predictors.len()
+    )?;
+    for (i, (predictor, matched)) in predictors.iter().zip(matches).enumerate() {
+        let status = if matched { "matched" } else { "not matched" };
+        write!(writer, "  Branch {} ({status}): ", i + 1)?;
+        report_child(predictor.as_ref(), game_data, locale, state, writer)?;
+    }
+    Ok(())
+}
+
+fn report_branches_structured(
+    predictors: &[Box<dyn Predictor>],
+    game_data: &GameData,
+    locale: &Locale,
+    state: &PredictionGameState,
+) -> Result<BranchesReport> {
+    let branches = predictors
+        .iter()
+        .map(|predictor| {
+            Ok(BranchReport {
+                matched: predictor.predict(state)?,
+                report: predictor.report_structured(game_data, locale, state)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(BranchesReport { branches })
+}
+
+fn explain_branches(
+    predictors: &[Box<dyn Predictor>],
+    game_data: &GameData,
+    locale: &Locale,
+    state: &PredictionGameState,
+) -> Result<Vec<Explanation>> {
+    predictors
+        .iter()
+        .map(|predictor| predictor.explain(game_data, locale, state))
+        .collect()
+}
+
+/// Succeeds if any of its child [`Predictor`]s succeed.
+///
+/// Configured through [`AnyOfConfig`].
+///
+/// Children are evaluated in order and evaluation stops at the first match.
+pub struct AnyOf<G: Sync + SeedGenerator> {
+    predictors: Vec<Box<dyn Predictor>>,
+    phantom: PhantomData<G>,
+}
+
+impl<G: 'static + Send + Sync + SeedGenerator> AnyOf<G> {
+    /// Create a new [`AnyOf`] predictor from an [`AnyOfConfig`].
+    pub fn new(game_data: &GameData, locale: &Locale, config: &AnyOfConfig) -> Result<Self> {
+        Ok(Self {
+            predictors: children::<G>(game_data, locale, &config.predictors)?,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Debug for AnyOf<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnyOf")
+            .field("predictors", &self.predictors)
+            .finish()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Predictor for AnyOf<G> {
+    fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+        for predictor in &self.predictors {
+            if predictor.predict(state)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Scores the best scoring child.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        let mut best = 0.0f64;
+        for predictor in &self.predictors {
+            best = best.max(predictor.score(state)?);
+        }
+        Ok(best)
+    }
+
+    fn report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        report_branches("Any Of", &self.predictors, game_data, locale, state, writer)
+    }
+
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let report = report_branches_structured(&self.predictors, game_data, locale, state)?;
+        Ok(PredictorReport::AnyOf(report))
+    }
+
+    fn explain(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let children = explain_branches(&self.predictors, game_data, locale, state)?;
+        let matches = children.iter().filter(|child| child.matched).count();
+        Ok(Explanation {
+            matched: matches > 0,
+            detail: Some(format!(
+                "Any Of: {matches} of {} branches matched",
+                children.len()
+            )),
+            children,
+        })
+    }
+}
+
+/// Succeeds only if all of its child [`Predictor`]s succeed.
+///
+/// Configured through [`AllOfConfig`].
+///
+/// Useful for grouping predictors inside of an [`AnyOf`] or [`Not`].
+/// Children are evaluated in order and evaluation stops at the first failure.
+pub struct AllOf<G: Sync + SeedGenerator> {
+    predictors: Vec<Box<dyn Predictor>>,
+    phantom: PhantomData<G>,
+}
+
+impl<G: 'static + Send + Sync + SeedGenerator> AllOf<G> {
+    /// Create a new [`AllOf`] predictor from an [`AllOfConfig`].
+    pub fn new(game_data: &GameData, locale: &Locale, config: &AllOfConfig) -> Result<Self> {
+        Ok(Self {
+            predictors: children::<G>(game_data, locale, &config.predictors)?,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Debug for AllOf<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AllOf")
+            .field("predictors", &self.predictors)
+            .finish()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Predictor for AllOf<G> {
+    fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+        for predictor in &self.predictors {
+            if !predictor.predict(state)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Scores the mean of the child scores.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        if self.predictors.is_empty() {
+            return Ok(1.0);
+        }
+
+        let mut total = 0.0;
+        for predictor in &self.predictors {
+            total += predictor.score(state)?;
+        }
+        Ok(total / self.predictors.len() as f64)
+    }
+
+    fn report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        report_branches("All Of", &self.predictors, game_data, locale, state, writer)
+    }
+
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let report = report_branches_structured(&self.predictors, game_data, locale, state)?;
+        Ok(PredictorReport::AllOf(report))
+    }
+
+    fn explain(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let children = explain_branches(&self.predictors, game_data, locale, state)?;
+        let matches = children.iter().filter(|child| child.matched).count();
+        Ok(Explanation {
+            matched: matches == children.len(),
+            detail: Some(format!(
+                "All Of: {matches} of {} branches matched",
+                children.len()
+            )),
+            children,
+        })
+    }
+}
+
+/// Inverts the result of a child [`Predictor`].
+///
+/// Configured through [`NotConfig`].
+pub struct Not<G: Sync + SeedGenerator> {
+    child: Box<dyn Predictor>,
+    phantom: PhantomData<G>,
+}
+
+impl<G: 'static + Send + Sync + SeedGenerator> Not<G> {
+    /// Create a new [`Not`] predictor from a [`NotConfig`].
+    pub fn new(game_data: &GameData, locale: &Locale, config: &NotConfig) -> Result<Self> {
+        Ok(Self {
+            child: config.child.predictor::<G>(game_data, locale)?,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Debug for Not<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Not").field("child", &self.child).finish()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Predictor for Not<G> {
+    fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+        Ok(!self.child.predict(state)?)
+    }
+
+    fn report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        let status = if self.child.predict(state)? {
+            "matched"
+        } else {
+            "not matched"
+        };
+        write!(writer, "Not (child {status}): ")?;
+        report_child(self.child.as_ref(), game_data, locale, state, writer)
+    }
+
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        Ok(PredictorReport::Not(NotReport {
+            child_matched: self.child.predict(state)?,
+            child: Box::new(self.child.report_structured(game_data, locale, state)?),
+        }))
+    }
+
+    fn explain(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let child = self.child.explain(game_data, locale, state)?;
+        Ok(Explanation {
+            matched: !child.matched,
+            detail: Some("Not: child must not match".to_string()),
+            children: vec![child],
+        })
+    }
+}
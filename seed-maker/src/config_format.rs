@@ -0,0 +1,153 @@
+use std::{fmt, path::Path};
+
+use anyhow::{anyhow, bail};
+use serde::{ser::Error, Deserializer, Serialize, Serializer};
+
+use crate::{Result, SeedFinderConfig};
+
+/// File format of a [`SeedFinderConfig`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// JSON, used for `.json` files.
+    Json,
+
+    /// TOML, used for `.toml` files.
+    Toml,
+
+    /// YAML, used for `.yaml` and `.yml` files.
+    Yaml,
+
+    /// RON, used for `.ron` files.
+    Ron,
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ConfigFormat::Json => "JSON",
+            ConfigFormat::Toml => "TOML",
+            ConfigFormat::Yaml => "YAML",
+            ConfigFormat::Ron => "RON",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Deserialize a config, reporting the path of the field that failed.
+fn deserialize<'de, D>(deserializer: D) -> Result<SeedFinderConfig>
+where
+    D: Deserializer<'de>,
+    D::Error: Send + Sync + 'static,
+{
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|e| anyhow!("{}: {}", e.path(), e.inner()))
+}
+
+/// Serialize a unit enum variant as a string.
+///
+/// RON writes unit variants as bare identifiers, which it can't read back
+/// inside internally tagged enums like [`PredictorConfig`](crate::PredictorConfig).
+/// Other formats already write them as strings so their output is unchanged.
+pub(crate) fn serialize_variant_name<T: Serialize, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serde_json::to_value(value)
+        .map_err(S::Error::custom)?
+        .serialize(serializer)
+}
+
+impl ConfigFormat {
+    /// Pick a format based on the extension of `path`.
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Ok(ConfigFormat::Json),
+            "toml" => Ok(ConfigFormat::Toml),
+            "yaml" | "yml" => Ok(ConfigFormat::Yaml),
+            "ron" => Ok(ConfigFormat::Ron),
+            _ => bail!(
+                "unknown config format for {}, expected a .json, .toml, .yaml, .yml or .ron file",
+                path.display()
+            ),
+        }
+    }
+
+    /// Parse a config in this format.
+    ///
+    /// Errors include the path of the offending field.
+    pub fn parse(self, data: &str) -> Result<SeedFinderConfig> {
+        match self {
+            ConfigFormat::Json => deserialize(&mut serde_json::Deserializer::from_str(data)),
+            ConfigFormat::Toml => deserialize(toml::Deserializer::new(data)),
+            ConfigFormat::Yaml => deserialize(serde_yaml::Deserializer::from_str(data)),
+            ConfigFormat::Ron => {
+                let mut deserializer = ron::Deserializer::from_str(data)?;
+                let config = deserialize(&mut deserializer)?;
+                deserializer.end()?;
+                Ok(config)
+            }
+        }
+    }
+
+    /// Serialize a config in this format.
+    ///
+    /// The result can be read back with [`ConfigFormat::parse`].
+    pub fn serialize(self, config: &SeedFinderConfig) -> Result<String> {
+        let data = match self {
+            ConfigFormat::Json => serde_json::to_string_pretty(config)? + "\n",
+            ConfigFormat::Toml => toml::to_string_pretty(config)?,
+            ConfigFormat::Yaml => serde_yaml::to_string(config)?,
+            ConfigFormat::Ron => {
+                ron::ser::to_string_pretty(config, ron::ser::PrettyConfig::default())? + "\n"
+            }
+        };
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMATS: [ConfigFormat; 4] = [
+        ConfigFormat::Json,
+        ConfigFormat::Toml,
+        ConfigFormat::Yaml,
+        ConfigFormat::Ron,
+    ];
+
+    #[test]
+    fn round_trip() {
+        let config: SeedFinderConfig = serde_json::from_str(
+            r#"{
+                "max_seeds": 10,
+                "game_state": { "day": "Y1 Spring 5" },
+                "predictors": [
+                    { "type": "weather", "is_rain": true },
+                    {
+                        "type": "geode_plan",
+                        "geodes": [{ "geode_type": "omni_geode", "item": "Prismatic Shard" }]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let expected = serde_json::to_value(&config).unwrap();
+        for format in FORMATS {
+            let data = format.serialize(&config).unwrap();
+            let parsed = format.parse(&data).unwrap();
+            assert_eq!(serde_json::to_value(&parsed).unwrap(), expected, "{format}");
+        }
+    }
+
+    #[test]
+    fn display_name() {
+        let names = FORMATS.map(|format| format.to_string());
+        assert_eq!(names, ["JSON", "TOML", "YAML", "RON"]);
+    }
+}
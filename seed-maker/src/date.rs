@@ -0,0 +1,459 @@
+use std::{borrow::Cow, fmt, str::FromStr};
+
+use anyhow::{anyhow, bail};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::{
+    de::{self, value::MapAccessDeserializer, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
+
+use crate::Result;
+
+/// Number of days in each season.
+const DAYS_PER_SEASON: u32 = 28;
+
+/// Number of days in each year.
+const DAYS_PER_YEAR: u32 = DAYS_PER_SEASON * 4;
+
+/// A season of the year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, JsonSchema, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Season {
+    /// Spring, the first season of the year.
+    Spring,
+
+    /// Summer.
+    Summer,
+
+    /// Fall.
+    Fall,
+
+    /// Winter, the last season of the year.
+    Winter,
+}
+
+impl Season {
+    const ALL: [Season; 4] = [Season::Spring, Season::Summer, Season::Fall, Season::Winter];
+
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Fall => "Fall",
+            Season::Winter => "Winter",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Season {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Season::ALL
+            .into_iter()
+            .find(|season| season.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| anyhow!("unknown season \"{s}\""))
+    }
+}
+
+/// A calendar date.
+///
+/// Written in configs either as an object or as a string like
+/// `"Y2 Summer 14"`.
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "season": "summer",
+///    "day": 14,
+///    "year": 2
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Date {
+    /// Year, starting at 1.
+    pub year: u32,
+
+    /// Season of the year.
+    #[serde(serialize_with = "crate::config_format::serialize_variant_name")]
+    pub season: Season,
+
+    /// Day of the season, between 1 and 28.
+    pub day: u32,
+}
+
+impl Date {
+    /// Create a new [`Date`], checking that it exists.
+    ///
+    /// Dates too far in the future for their `days_played` counter to fit in
+    /// a `u32` are rejected.
+    pub fn new(year: u32, season: Season, day: u32) -> Result<Self> {
+        if year == 0 {
+            bail!("year must be at least 1");
+        }
+        if !(1..=DAYS_PER_SEASON).contains(&day) {
+            bail!("day must be between 1 and {DAYS_PER_SEASON}, got {day}");
+        }
+        let date = Self { year, season, day };
+        if date.checked_days_played().is_none() {
+            bail!("year {year} is too far in the future");
+        }
+        Ok(date)
+    }
+
+    /// Convert a `days_played` counter to a [`Date`].
+    ///
+    /// Day 0 is treated as Year 1, Spring 1.
+    pub fn from_days_played(days_played: u32) -> Self {
+        let days = days_played.saturating_sub(1);
+        Self {
+            year: days / DAYS_PER_YEAR + 1,
+            season: Season::ALL[(days % DAYS_PER_YEAR / DAYS_PER_SEASON) as usize],
+            day: days % DAYS_PER_SEASON + 1,
+        }
+    }
+
+    /// The `days_played` counter on this date.
+    ///
+    /// Saturates at `u32::MAX` for dates not created through [`Date::new`]
+    /// that are too far in the future to count.
+    pub fn days_played(&self) -> u32 {
+        self.checked_days_played().unwrap_or(u32::MAX)
+    }
+
+    fn checked_days_played(&self) -> Option<u32> {
+        self.year
+            .checked_sub(1)?
+            .checked_mul(DAYS_PER_YEAR)?
+            .checked_add(self.season.index() * DAYS_PER_SEASON)?
+            .checked_add(self.day)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Y{} {} {}", self.year, self.season, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = anyhow::Error;
+
+    /// Parse dates like `Y2 Summer 14` or `Summer 14, Year 2`.
+    ///
+    /// The year defaults to 1 if omitted.  Giving the year, season or day more
+    /// than once is an error.
+    fn from_str(s: &str) -> Result<Self> {
+        fn set<T>(field: &mut Option<T>, value: T, name: &str, s: &str) -> Result<()> {
+            if field.replace(value).is_some() {
+                bail!("{name} given more than once in date \"{s}\"");
+            }
+            Ok(())
+        }
+
+        let mut year = None;
+        let mut season = None;
+        let mut day = None;
+
+        let mut tokens = s
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty());
+        while let Some(token) = tokens.next() {
+            let lower = token.to_ascii_lowercase();
+            if lower == "year" {
+                let value = tokens
+                    .next()
+                    .ok_or_else(|| anyhow!("missing year in date \"{s}\""))?;
+                set(&mut year, value.parse()?, "year", s)?;
+            } else if let Some(value) = lower.strip_prefix('y').filter(|v| !v.is_empty()) {
+                let value = value
+                    .parse()
+                    .map_err(|_| anyhow!("invalid year \"{token}\" in date \"{s}\""))?;
+                set(&mut year, value, "year", s)?;
+            } else if let Ok(value) = token.parse() {
+                set(&mut day, value, "day", s)?;
+            } else {
+                set(&mut season, token.parse()?, "season", s)?;
+            }
+        }
+
+        Date::new(
+            year.unwrap_or(1),
+            season.ok_or_else(|| anyhow!("missing season in date \"{s}\""))?,
+            day.ok_or_else(|| anyhow!("missing day in date \"{s}\""))?,
+        )
+    }
+}
+
+/// Object form of a [`Date`] in configs.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DateFields {
+    #[serde(default = "crate::one")]
+    year: u32,
+    #[serde(deserialize_with = "deserialize_season")]
+    season: Season,
+    day: u32,
+}
+
+/// Read a season name as a string so it parses the same whether or not the
+/// date is nested inside a tagged [`PredictorConfig`](crate::PredictorConfig).
+fn deserialize_season<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Season, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    name.parse().map_err(de::Error::custom)
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_any(DayVisitor)
+            .and_then(|day| match day {
+                Day::Date(date) => Ok(date),
+                Day::DaysPlayed(_) => Err(de::Error::custom("expected a date")),
+            })
+    }
+}
+
+/// Pattern accepted by [`Date::from_str`].
+///
+/// JSON Schema patterns don't support case insensitive matching so only
+/// lowercase and capitalized words are accepted.
+const DATE_PATTERN: &str =
+    r"^(\s*([Yy]\d+|[Yy]ear\s+\d+|[Ss]pring|[Ss]ummer|[Ff]all|[Ww]inter|\d+)\s*,?)+$";
+
+impl JsonSchema for Date {
+    fn schema_name() -> Cow<'static, str> {
+        "Date".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let season = generator.subschema_for::<Season>();
+        json_schema!({
+            "description": "A calendar date like \"Y2 Summer 14\" or an object.",
+            "oneOf": [
+                {
+                    "type": "string",
+                    "pattern": DATE_PATTERN,
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "year": { "type": "integer", "minimum": 1, "default": 1 },
+                        "season": season,
+                        "day": { "type": "integer", "minimum": 1, "maximum": DAYS_PER_SEASON },
+                    },
+                    "required": ["season", "day"],
+                    "additionalProperties": false,
+                },
+            ],
+        })
+    }
+}
+
+/// A day configured either as a `days_played` counter or as a [`Date`].
+///
+/// ## Example JSON
+/// ```text
+/// 42
+/// "Y2 Summer 14"
+/// { "season": "summer", "day": 14, "year": 2 }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Day {
+    /// Raw `days_played` counter.
+    ///
+    /// Starts as 1 on Year 1, Spring 1.  Increments every day.  Does not reset
+    /// at the begining of month or year.
+    DaysPlayed(u32),
+
+    /// Calendar date.
+    Date(Date),
+}
+
+impl Day {
+    /// The `days_played` counter on this day.
+    pub fn days_played(&self) -> u32 {
+        match self {
+            Day::DaysPlayed(days_played) => *days_played,
+            Day::Date(date) => date.days_played(),
+        }
+    }
+}
+
+impl From<u32> for Day {
+    fn from(days_played: u32) -> Self {
+        Day::DaysPlayed(days_played)
+    }
+}
+
+struct DayVisitor;
+
+impl<'de> Visitor<'de> for DayVisitor {
+    type Value = Day;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a days played number, a date string like \"Y2 Summer 14\" or a date object")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Day, E> {
+        let days_played = u32::try_from(value).map_err(E::custom)?;
+        Ok(Day::DaysPlayed(days_played))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Day, E> {
+        let days_played = u32::try_from(value).map_err(E::custom)?;
+        Ok(Day::DaysPlayed(days_played))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Day, E> {
+        value.parse().map(Day::Date).map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Day, A::Error> {
+        let fields = DateFields::deserialize(MapAccessDeserializer::new(map))?;
+        Date::new(fields.year, fields.season, fields.day)
+            .map(Day::Date)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl JsonSchema for Day {
+    fn schema_name() -> Cow<'static, str> {
+        "Day".into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let date = generator.subschema_for::<Date>();
+        json_schema!({
+            "description": "A days played counter starting at 1 on Year 1, Spring 1, or a calendar date.",
+            "anyOf": [
+                { "type": "integer", "minimum": 0 },
+                date,
+            ],
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Day {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DayVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u32, season: Season, day: u32) -> Date {
+        Date::new(year, season, day).unwrap()
+    }
+
+    #[test]
+    fn parses_date_forms() {
+        let expected = date(2, Season::Summer, 14);
+        for s in [
+            "Y2 Summer 14",
+            "y2 summer 14",
+            "Summer 14, Year 2",
+            "summer 14 y2",
+            "14 Summer Y2",
+        ] {
+            assert_eq!(s.parse::<Date>().unwrap(), expected, "{s}");
+        }
+        assert_eq!("Fall 3".parse::<Date>().unwrap(), date(1, Season::Fall, 3));
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        for s in [
+            "",
+            "Summer",
+            "14",
+            "Y2 14",
+            "Y0 Summer 14",
+            "Summer 29",
+            "Summer 0",
+            "Y2 Sumer 14",
+            "Yx Summer 14",
+            "Summer 14, Year",
+            "Y4294967295 Winter 28",
+        ] {
+            assert!(s.parse::<Date>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn rejects_repeated_fields() {
+        for s in [
+            "Summer 14 15",
+            "Y1 Y3 Fall 2",
+            "Y1 Fall 2, Year 3",
+            "Summer Fall 2",
+        ] {
+            let error = s.parse::<Date>().unwrap_err().to_string();
+            assert!(error.contains("more than once"), "{s}: {error}");
+        }
+    }
+
+    #[test]
+    fn days_played_round_trip() {
+        assert_eq!(date(1, Season::Spring, 1).days_played(), 1);
+        assert_eq!(date(1, Season::Winter, 28).days_played(), 112);
+        assert_eq!(date(2, Season::Summer, 14).days_played(), 154);
+        assert_eq!(Date::from_days_played(0), date(1, Season::Spring, 1));
+
+        for days_played in (1..=1000).chain([u32::MAX - 1, u32::MAX]) {
+            let date = Date::from_days_played(days_played);
+            assert_eq!(date.days_played(), days_played, "{date}");
+            assert_eq!(Date::new(date.year, date.season, date.day).unwrap(), date);
+        }
+    }
+
+    #[test]
+    fn rejects_dates_past_days_played_range() {
+        let last = Date::from_days_played(u32::MAX);
+        assert!(Date::new(last.year + 1, Season::Spring, 1).is_err());
+        assert!(Date::new(u32::MAX, Season::Winter, 28).is_err());
+    }
+
+    #[test]
+    fn deserializes_day_forms() {
+        let parse = |json: &str| serde_json::from_str::<Day>(json);
+        let expected = Day::Date(date(2, Season::Summer, 14));
+
+        assert_eq!(parse("42").unwrap(), Day::DaysPlayed(42));
+        assert_eq!(parse("\"Y2 Summer 14\"").unwrap(), expected);
+        assert_eq!(
+            parse(r#"{ "season": "summer", "day": 14, "year": 2 }"#).unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse(r#"{ "season": "Summer", "day": 14 }"#).unwrap(),
+            Day::Date(date(1, Season::Summer, 14))
+        );
+        assert_eq!(expected.days_played(), 154);
+
+        assert!(parse("-1").is_err());
+        assert!(parse("4294967296").is_err());
+        assert!(parse("\"Summer 14 15\"").is_err());
+        assert!(parse(r#"{ "season": "summer", "day": 29 }"#).is_err());
+        assert!(parse(r#"{ "season": "summer", "day": 14, "month": 2 }"#).is_err());
+    }
+
+    #[test]
+    fn date_rejects_days_played() {
+        assert!(serde_json::from_str::<Date>("42").is_err());
+        assert_eq!(
+            serde_json::from_str::<Date>("\"Y2 Summer 14\"").unwrap(),
+            date(2, Season::Summer, 14)
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    ops::RangeInclusive,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context};
+use rayon::prelude::*;
+use sdv::predictor::PredictionGameState;
+
+use crate::{Predictor, Result};
+
+/// Z score of a 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+/// An estimated value with a 95% confidence interval.
+#[derive(Clone, Copy, Debug)]
+pub struct Interval {
+    /// Best estimate of the value.
+    pub estimate: f64,
+
+    /// Lower bound of the confidence interval.
+    pub low: f64,
+
+    /// Upper bound of the confidence interval.
+    pub high: f64,
+}
+
+impl Interval {
+    /// Wilson score interval of `successes` out of `trials`.
+    fn wilson(successes: usize, trials: usize) -> Self {
+        let n = trials as f64;
+        let p = successes as f64 / n;
+        let z2 = Z_95 * Z_95;
+        let denominator = 1.0 + z2 / n;
+        let center = (p + z2 / (2.0 * n)) / denominator;
+        let half_width = Z_95 / denominator * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt();
+        Self {
+            estimate: p,
+            low: (center - half_width).max(0.0),
+            high: (center + half_width).min(1.0),
+        }
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        Self {
+            estimate: self.estimate * factor,
+            low: self.low * factor,
+            high: self.high * factor,
+        }
+    }
+}
+
+/// Estimated pass rate and cost of a single predictor.
+#[derive(Clone, Debug)]
+pub struct PredictorEstimate {
+    /// Fraction of seeds expected to match, between 0.0 and 1.0.
+    pub pass_rate: Interval,
+
+    /// Average time taken to check one seed.
+    pub cost: Duration,
+}
+
+/// Projected results of a search.
+///
+/// Produced by [`SeedFinder::estimate`](crate::SeedFinder::estimate).
+#[derive(Clone, Debug)]
+pub struct Estimate {
+    /// Number of seeds sampled.
+    pub samples: usize,
+
+    /// Number of sampled seeds that matched every predictor.
+    pub sample_matches: usize,
+
+    /// Fraction of seeds expected to match every predictor.
+    pub match_rate: Interval,
+
+    /// Expected number of matching seeds in the whole search range.
+    pub total_matches: Interval,
+
+    /// Estimates for each predictor, in configuration order.
+    pub predictors: Vec<PredictorEstimate>,
+
+    /// Expected wall clock time of the search in seconds.
+    ///
+    /// Accounts for the search stopping after `max_seeds` matches and for the
+    /// number of threads available.
+    pub search_seconds: Interval,
+}
+
+/// Timing and results of running every predictor against one seed.
+struct Sample {
+    /// Time and result of each predictor in configuration order.
+    results: Vec<(Duration, bool)>,
+}
+
+impl Sample {
+    fn matched(&self) -> bool {
+        self.results.iter().all(|(_, matched)| *matched)
+    }
+
+    /// Time a search would spend on this seed, stopping at the first
+    /// predictor in `order` that doesn't match.
+    fn search_cost(&self, order: &[usize]) -> Duration {
+        let mut cost = Duration::ZERO;
+        for &i in order {
+            let (elapsed, matched) = self.results[i];
+            cost += elapsed;
+            if !matched {
+                break;
+            }
+        }
+        cost
+    }
+}
+
+/// SplitMix64 used to spread samples over the seed range.
+///
+/// A fixed sequence keeps estimates of the same config reproducible.
+fn splitmix64(index: u64) -> u64 {
+    let mut z = index.wrapping_add(1).wrapping_mul(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+impl Estimate {
+    /// Run every predictor against `samples` pseudo-random seeds from
+    /// `seed_range` and project the results onto the whole search.
+    ///
+    /// `order` is the order predictors are evaluated in while searching and
+    /// `max_seeds` is `None` if the search always scans the whole range.
+    pub(crate) fn measure(
+        predictors: &[Box<dyn Predictor>],
+        initial_state: &PredictionGameState,
+        seed_range: &RangeInclusive<i32>,
+        order: &[usize],
+        max_seeds: Option<usize>,
+        samples: usize,
+    ) -> Result<Self> {
+        if samples == 0 {
+            bail!("can't estimate from 0 samples");
+        }
+
+        let start = *seed_range.start() as i64;
+        let count = (*seed_range.end() as i64 - start + 1) as u64;
+
+        let results = (0..samples as u64)
+            .into_par_iter()
+            .map(|sample| {
+                let offset = ((splitmix64(sample) as u128 * count as u128) >> 64) as i64;
+                let seed = (start + offset) as i32;
+                let state = PredictionGameState {
+                    game_id: seed as u32,
+                    ..*initial_state
+                };
+                let results = predictors
+                    .iter()
+                    .enumerate()
+                    .map(|(i, predictor)| {
+                        let started = Instant::now();
+                        let matched = predictor
+                            .predict(&state)
+                            .with_context(|| format!("predictors[{i}] failed on seed {seed}"))?;
+                        Ok((started.elapsed(), matched))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Sample { results })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let sample_matches = results.iter().filter(|sample| sample.matched()).count();
+        let match_rate = Interval::wilson(sample_matches, samples);
+
+        let estimates = (0..predictors.len())
+            .map(|i| {
+                let passed = results.iter().filter(|sample| sample.results[i].1).count();
+                let elapsed = results
+                    .iter()
+                    .map(|sample| sample.results[i].0)
+                    .sum::<Duration>();
+                PredictorEstimate {
+                    pass_rate: Interval::wilson(passed, samples),
+                    cost: elapsed / samples as u32,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Ranked searches score every predictor on every seed.
+        let per_seed = match max_seeds {
+            Some(_) => results
+                .iter()
+                .map(|sample| sample.search_cost(order))
+                .sum::<Duration>(),
+            None => results
+                .iter()
+                .flat_map(|sample| sample.results.iter().map(|(elapsed, _)| *elapsed))
+                .sum::<Duration>(),
+        }
+        .as_secs_f64()
+            / samples as f64;
+        let threads = rayon::current_num_threads() as f64;
+        let search_seconds = |rate: f64| {
+            let scanned = match max_seeds {
+                Some(max_seeds) if rate > 0.0 => (max_seeds as f64 / rate).min(count as f64),
+                _ => count as f64,
+            };
+            scanned * per_seed / threads
+        };
+
+        Ok(Self {
+            samples,
+            sample_matches,
+            match_rate,
+            total_matches: match_rate.scale(count as f64),
+            predictors: estimates,
+            // More matches means the search can stop sooner.
+            search_seconds: Interval {
+                estimate: search_seconds(match_rate.estimate),
+                low: search_seconds(match_rate.high),
+                high: search_seconds(match_rate.low),
+            },
+        })
+    }
+}
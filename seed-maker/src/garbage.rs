@@ -1,5 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use schemars::JsonSchema;
 use sdv::{
     common::{items, ItemId},
     predictor::{
@@ -12,7 +13,7 @@ use sdv::{
 use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 
-use crate::{Predictor, Result};
+use crate::{item_names::resolve_item, Explanation, Predictor, PredictorReport, Result};
 
 /// Configuration for [`Garbage`].
 ///
@@ -21,17 +22,51 @@ use crate::{Predictor, Result};
 /// {
 ///    "type": "garbage",
 ///    "items": [
-///        "(O)535",
+///        "Geode",
 ///        "DISH_OF_THE_DAY"
 ///    ]
 /// }
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
 pub struct GarbageConfig {
     /// List of items required to be found in garbage cans.
+    ///
+    /// Each item is either a display name like `Geode`, a qualified ID like
+    /// `(O)535` or `DISH_OF_THE_DAY`.
     pub items: Vec<String>,
 }
 
+/// Structured report of a [`Garbage`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GarbageReport {
+    /// Drops from each garbage can that has one.
+    pub cans: Vec<GarbageCanReport>,
+}
+
+/// Structured report of a single garbage can's drop.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GarbageCanReport {
+    /// Location of the garbage can.
+    pub location: String,
+
+    /// Display name of the dropped item.
+    pub item: String,
+
+    /// Number of items dropped.
+    pub quantity: u32,
+
+    /// Minimum daily luck needed to get the drop.
+    pub min_luck: f64,
+}
+
+fn item_name<'a>(game_data: &'a GameData, locale: &'a Locale, item: &ItemId) -> Result<&'a str> {
+    if *item == items::DISH_OF_THE_DAY {
+        Ok("Dish of the Day")
+    } else {
+        Ok(game_data.get_object_by_id(item)?.display_name(locale))
+    }
+}
+
 /// Predictor for garbage cans in town.
 #[derive(Clone)]
 pub struct Garbage<G: Send + Sync + SeedGenerator> {
@@ -42,14 +77,14 @@ pub struct Garbage<G: Send + Sync + SeedGenerator> {
 
 impl<G: Send + Sync + SeedGenerator> Garbage<G> {
     /// Create a new [`Garbage`] from a [`GarbageConfig`].
-    pub fn new(game_data: &GameData, config: &GarbageConfig) -> Result<Self> {
+    pub fn new(game_data: &GameData, locale: &Locale, config: &GarbageConfig) -> Result<Self> {
         let cans: Vec<_> = GarbageCanLocation::iter()
             .map(|location| GarbageCan::new(location, &game_data.garbage_cans))
             .collect::<Result<Vec<_>>>()?;
         let items = config
             .items
             .iter()
-            .map(|name| name.parse::<ItemId>())
+            .map(|name| resolve_item(game_data, locale, name))
             .collect::<Result<Vec<_>>>()?;
         Ok(Self {
             items,
@@ -59,6 +94,24 @@ impl<G: Send + Sync + SeedGenerator> Garbage<G> {
     }
 }
 
+impl<G: Send + Sync + SeedGenerator> Garbage<G> {
+    /// Whether each required item is dropped by any garbage can, in
+    /// configuration order.
+    fn found_items(&self, state: &PredictionGameState) -> Result<Vec<bool>> {
+        let mut results = Vec::new();
+        for can in &self.cans {
+            if let Some(prediction) = predict_garbage::<G>(can, state)? {
+                results.push(prediction.0);
+            }
+        }
+        Ok(self
+            .items
+            .iter()
+            .map(|item| results.iter().any(|drop| drop.item == *item))
+            .collect())
+    }
+}
+
 impl<G: Send + Sync + SeedGenerator> Debug for Garbage<G> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Garbage")
@@ -70,18 +123,39 @@ impl<G: Send + Sync + SeedGenerator> Debug for Garbage<G> {
 
 impl<G: Send + Sync + SeedGenerator> Predictor for Garbage<G> {
     fn predict(&self, state: &PredictionGameState) -> Result<bool> {
-        let mut results = Vec::new();
-        for can in &self.cans {
-            if let Some(prediction) = predict_garbage::<G>(can, state)? {
-                results.push(prediction.0);
-            }
-        }
-        for item in &self.items {
-            if !results.iter().any(|drop| drop.item == *item) {
-                return Ok(false);
-            }
+        Ok(self.found_items(state)?.iter().all(|found| *found))
+    }
+
+    /// Scores the fraction of required items found.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        if self.items.is_empty() {
+            return Ok(1.0);
         }
-        Ok(true)
+
+        let found = self
+            .found_items(state)?
+            .iter()
+            .filter(|found| **found)
+            .count();
+        Ok(found as f64 / self.items.len() as f64)
+    }
+
+    /// Buckets seeds by the number of required items found.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let found = self
+            .found_items(state)?
+            .iter()
+            .filter(|found| **found)
+            .count();
+        Ok((
+            found == self.items.len(),
+            format!("{found} of {} items found", self.items.len()),
+        ))
     }
 
     fn report(
@@ -94,17 +168,56 @@ impl<G: Send + Sync + SeedGenerator> Predictor for Garbage<G> {
         writeln!(writer, "Garbage:")?;
         for can in &self.cans {
             if let Some((drop, min_luck)) = predict_garbage::<G>(can, state)? {
-                let item_name = match drop.item {
-                    items::DISH_OF_THE_DAY => "Dish of the Day",
-                    item => game_data.get_object_by_id(&item)?.display_name(locale),
-                };
                 writeln!(
                     writer,
                     "  {}: {} {} (minluck: {:.3})",
-                    can.location, drop.quantity, item_name, min_luck
+                    can.location,
+                    drop.quantity,
+                    item_name(game_data, locale, &drop.item)?,
+                    min_luck
                 )?
             }
         }
         Ok(())
     }
+
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let mut cans = Vec::new();
+        for can in &self.cans {
+            if let Some((drop, min_luck)) = predict_garbage::<G>(can, state)? {
+                cans.push(GarbageCanReport {
+                    location: can.location.to_string(),
+                    item: item_name(game_data, locale, &drop.item)?.to_string(),
+                    quantity: drop.quantity,
+                    min_luck,
+                });
+            }
+        }
+        Ok(PredictorReport::Garbage(GarbageReport { cans }))
+    }
+
+    fn explain(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let mut missing = Vec::new();
+        for (item, found) in self.items.iter().zip(self.found_items(state)?) {
+            if !found {
+                missing.push(item_name(game_data, locale, item)?);
+            }
+        }
+        let detail = if missing.is_empty() {
+            format!("Garbage: all {} items found", self.items.len())
+        } else {
+            format!("Garbage: missing {}", missing.join(", "))
+        };
+        Ok(Explanation::new(missing.is_empty(), Some(detail)))
+    }
 }
@@ -1,5 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use schemars::JsonSchema;
 use sdv::{
     common::ItemId,
     predictor::{
@@ -12,7 +13,7 @@ use sdv::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{Predictor, Result};
+use crate::{item_names::resolve_item, Explanation, Predictor, PredictorReport, Result};
 
 /// Configurations for [`Geode`].
 ///
@@ -20,21 +21,123 @@ use crate::{Predictor, Result};
 /// ```text
 /// {
 ///    "type": "geode",
-///    "item": "(O)378",
+///    "item": "Copper Ore",
 ///    "quantity": 20,
 ///    "geode_type": "geode"
 /// }
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
 pub struct GeodeConfig {
     /// Item to search for.
+    ///
+    /// Either a display name like `Copper Ore` or a qualified ID like
+    /// `(O)378`.
     pub item: String,
 
     /// Minimum quanity required.
     pub quantity: u32,
 
     /// Type of geode used for search.
+    #[schemars(schema_with = "crate::schema::geode_type_schema")]
+    #[serde(serialize_with = "crate::config_format::serialize_variant_name")]
+    pub geode_type: GeodeType,
+}
+
+/// Structured report of a [`Geode`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GeodeReport {
+    /// Type of geode cracked.
     pub geode_type: GeodeType,
+
+    /// Display name of the item received.
+    pub item: String,
+
+    /// Number of items received.
+    pub quantity: u32,
+}
+
+/// Structured report of a single cracked geode in a sequence of geodes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GeodeCrackReport {
+    /// `geodes_cracked` counter of the geode.
+    pub geodes_cracked: u32,
+
+    /// Display name of the item received.
+    pub item: String,
+
+    /// Number of items received.
+    pub quantity: u32,
+}
+
+/// Contents of a single cracked geode.
+pub(crate) struct Crack {
+    pub(crate) geodes_cracked: u32,
+    pub(crate) item: ItemId,
+    pub(crate) quantity: u32,
+}
+
+impl Crack {
+    /// Crack `geode` with the `geodes_cracked` counter of `state` replaced by
+    /// `geodes_cracked`.
+    pub(crate) fn new<G: SeedGenerator>(
+        geode: &predictor::geode::Geode,
+        state: &PredictionGameState,
+        geodes_cracked: u32,
+    ) -> Result<Self> {
+        let state = PredictionGameState {
+            geodes_cracked,
+            ..*state
+        };
+        let reward = predict_single_geode::<G>(geode, &state)?;
+        Ok(Self {
+            geodes_cracked,
+            item: reward.item,
+            quantity: reward.quantity,
+        })
+    }
+
+    /// Display name of the item received.
+    pub(crate) fn item_name<'a>(
+        &self,
+        game_data: &'a GameData,
+        locale: &'a Locale,
+    ) -> Result<&'a str> {
+        Ok(game_data.get_object_by_id(&self.item)?.display_name(locale))
+    }
+
+    /// Write a report line for the crack, labelled with `geode_type` if given.
+    pub(crate) fn report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        geode_type: Option<GeodeType>,
+        writer: &mut dyn std::io::prelude::Write,
+    ) -> Result<()> {
+        let label = match geode_type {
+            Some(geode_type) => format!("{} {geode_type:?}", self.geodes_cracked),
+            None => self.geodes_cracked.to_string(),
+        };
+        writeln!(
+            writer,
+            "  {label}: {} {}",
+            self.quantity,
+            self.item_name(game_data, locale)?
+        )?;
+        Ok(())
+    }
+
+    /// Structured report of the crack.
+    pub(crate) fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+    ) -> Result<GeodeCrackReport> {
+        Ok(GeodeCrackReport {
+            geodes_cracked: self.geodes_cracked,
+            item: self.item_name(game_data, locale)?.to_string(),
+            quantity: self.quantity,
+        })
+    }
 }
 
 /// Predictor for items received by breaking geodes.
@@ -52,8 +155,8 @@ pub struct Geode<G: Send + Sync + SeedGenerator> {
 
 impl<G: Send + Sync + SeedGenerator> Geode<G> {
     /// Create a new [`Geode`] from a [`GeodeConfig`].
-    pub fn new(game_data: &GameData, config: &GeodeConfig) -> Result<Self> {
-        let item = config.item.parse()?;
+    pub fn new(game_data: &GameData, locale: &Locale, config: &GeodeConfig) -> Result<Self> {
+        let item = resolve_item(game_data, locale, &config.item)?;
         Ok(Self {
             item,
             quantity: config.quantity,
@@ -64,6 +167,27 @@ impl<G: Send + Sync + SeedGenerator> Geode<G> {
     }
 }
 
+impl<G: Send + Sync + SeedGenerator> Geode<G> {
+    /// Structured report of the geode cracked at `state`.
+    pub(crate) fn geode_report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<GeodeReport> {
+        let reward = predict_single_geode::<G>(&self.geode, state)?;
+        let item = game_data
+            .get_object_by_id(&reward.item)?
+            .display_name(locale)
+            .to_string();
+        Ok(GeodeReport {
+            geode_type: self.geode_type,
+            item,
+            quantity: reward.quantity,
+        })
+    }
+}
+
 impl<G: Send + Sync + SeedGenerator> Debug for Geode<G> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Geode")
@@ -98,4 +222,53 @@ impl<G: Send + Sync + SeedGenerator> Predictor for Geode<G> {
         )?;
         Ok(())
     }
+
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        Ok(PredictorReport::Geode(
+            self.geode_report(game_data, locale, state)?,
+        ))
+    }
+
+    /// Buckets seeds by the item received.
+    fn outcome(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let reward = predict_single_geode::<G>(&self.geode, state)?;
+        let item_name = game_data
+            .get_object_by_id(&reward.item)?
+            .display_name(locale);
+        Ok((
+            reward.item == self.item && reward.quantity >= self.quantity,
+            format!("{} {}", reward.quantity, item_name),
+        ))
+    }
+
+    fn explain(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let reward = predict_single_geode::<G>(&self.geode, state)?;
+        let item_name = game_data
+            .get_object_by_id(&reward.item)?
+            .display_name(locale);
+        let required_name = game_data.get_object_by_id(&self.item)?.display_name(locale);
+        let detail = format!(
+            "{:?}: got {} {}, need {} {}",
+            self.geode_type, reward.quantity, item_name, self.quantity, required_name
+        );
+        Ok(Explanation::new(
+            reward.item == self.item && reward.quantity >= self.quantity,
+            Some(detail),
+        ))
+    }
 }
@@ -0,0 +1,314 @@
+use std::{fmt::Debug, marker::PhantomData, ops::Range};
+
+use anyhow::bail;
+use schemars::JsonSchema;
+use sdv::{
+    common::ItemId,
+    predictor::{self, geode::GeodeType, PredictionGameState},
+    rng::SeedGenerator,
+    GameData, Locale,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geode::Crack, item_names::resolve_item, Explanation, GeodeCrackReport, Predictor,
+    PredictorReport, Result,
+};
+
+/// Configuration for [`GeodePlan`].
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "type": "geode_plan",
+///    "start_geodes_cracked": 1,
+///    "geodes": [
+///        { "geode_type": "geode" },
+///        { "geode_type": "frozen_geode", "item": "Frozen Tear" },
+///        { "geode_type": "omni_geode", "item": "Prismatic Shard" }
+///    ]
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct GeodePlanConfig {
+    /// `geodes_cracked` counter of the first geode in the plan.
+    ///
+    /// The game increments the counter before determining a geode's contents
+    /// so the first geode ever cracked is 1.  Defaults to 1.
+    #[serde(default = "crate::one")]
+    pub start_geodes_cracked: u32,
+
+    /// Geodes in the order they are cracked.
+    pub geodes: Vec<GeodePlanSlot>,
+}
+
+/// A single geode cracked as part of a [`GeodePlanConfig`].
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct GeodePlanSlot {
+    /// Type of geode cracked.
+    #[schemars(schema_with = "crate::schema::geode_type_schema")]
+    #[serde(serialize_with = "crate::config_format::serialize_variant_name")]
+    pub geode_type: GeodeType,
+
+    /// Item required from this geode.
+    ///
+    /// Either a display name like `Copper Ore` or a qualified ID like
+    /// `(O)378`.  Defaults to no requirement.
+    #[serde(default)]
+    pub item: Option<String>,
+
+    /// Minimum quantity of `item` required.
+    ///
+    /// Defaults to 1.
+    #[serde(default = "crate::one")]
+    pub quantity: u32,
+}
+
+/// Structured report of a [`GeodePlan`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GeodePlanReport {
+    /// Contents of every geode in the plan.
+    pub geodes: Vec<GeodePlanSlotReport>,
+}
+
+/// Structured report of a single geode in a [`GeodePlan`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GeodePlanSlotReport {
+    /// Type of geode cracked.
+    pub geode_type: GeodeType,
+
+    /// Contents of the geode.
+    #[serde(flatten)]
+    pub crack: GeodeCrackReport,
+}
+
+/// `geodes_cracked` counter after the last geode in the plan, or `None` if it
+/// overflows.
+pub(crate) fn plan_end(config: &GeodePlanConfig) -> Option<u32> {
+    let len = u32::try_from(config.geodes.len()).ok()?;
+    config.start_geodes_cracked.checked_add(len)
+}
+
+#[derive(Clone, Debug)]
+struct Slot {
+    /// Index into [`GeodePlan::geodes`].
+    geode: usize,
+    item: Option<ItemId>,
+    quantity: u32,
+}
+
+impl Slot {
+    fn is_met(&self, crack: &Crack) -> bool {
+        self.item
+            .as_ref()
+            .is_none_or(|item| crack.item == *item && crack.quantity >= self.quantity)
+    }
+}
+
+/// Predictor for items received by cracking a mix of geode types in order.
+///
+/// Configured through [`GeodePlanConfig`].
+///
+/// Every geode cracked increments the same `geodes_cracked` counter so the
+/// contents of each geode depend on the ones cracked before it.  Matches if
+/// every geode with a required item contains it.
+#[derive(Clone)]
+pub struct GeodePlan<G: Send + Sync + SeedGenerator> {
+    start_geodes_cracked: u32,
+    slots: Vec<Slot>,
+
+    /// One geode per type used in the plan.
+    geodes: Vec<(GeodeType, predictor::geode::Geode)>,
+
+    phantom: PhantomData<G>,
+}
+
+impl<G: Send + Sync + SeedGenerator> GeodePlan<G> {
+    /// Create a new [`GeodePlan`] from a [`GeodePlanConfig`].
+    pub fn new(game_data: &GameData, locale: &Locale, config: &GeodePlanConfig) -> Result<Self> {
+        if plan_end(config).is_none() {
+            bail!(
+                "plan of {} geodes starting at {} overflows geodes_cracked",
+                config.geodes.len(),
+                config.start_geodes_cracked
+            );
+        }
+        let mut geodes: Vec<(GeodeType, predictor::geode::Geode)> = Vec::new();
+        let mut slots = Vec::new();
+        for slot in &config.geodes {
+            let geode = match geodes.iter().position(|(t, _)| *t == slot.geode_type) {
+                Some(geode) => geode,
+                None => {
+                    let geode = predictor::geode::Geode::new(slot.geode_type, game_data)?;
+                    geodes.push((slot.geode_type, geode));
+                    geodes.len() - 1
+                }
+            };
+            let item = slot
+                .item
+                .as_ref()
+                .map(|name| resolve_item(game_data, locale, name))
+                .transpose()?;
+            slots.push(Slot {
+                geode,
+                item,
+                quantity: slot.quantity,
+            });
+        }
+        Ok(Self {
+            start_geodes_cracked: config.start_geodes_cracked,
+            slots,
+            geodes,
+            phantom: PhantomData,
+        })
+    }
+
+    /// `geodes_cracked` counter of each geode in the plan.
+    fn geodes_cracked(&self) -> Range<u32> {
+        // `new()` checked that the plan doesn't overflow.
+        self.start_geodes_cracked..self.start_geodes_cracked + self.slots.len() as u32
+    }
+
+    /// Contents of every geode in the plan.
+    fn cracks(&self, state: &PredictionGameState) -> Result<Vec<Crack>> {
+        let mut cracks = Vec::with_capacity(self.slots.len());
+        for (geodes_cracked, slot) in self.geodes_cracked().zip(&self.slots) {
+            cracks.push(Crack::new::<G>(
+                &self.geodes[slot.geode].1,
+                state,
+                geodes_cracked,
+            )?);
+        }
+        Ok(cracks)
+    }
+
+    /// Number of slots with a required item and number of those met.
+    fn met_slots(&self, state: &PredictionGameState) -> Result<(usize, usize)> {
+        let mut met = 0;
+        let mut required = 0;
+        for (slot, crack) in self.slots.iter().zip(self.cracks(state)?) {
+            if slot.item.is_some() {
+                required += 1;
+                if slot.is_met(&crack) {
+                    met += 1;
+                }
+            }
+        }
+        Ok((met, required))
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Debug for GeodePlan<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeodePlan")
+            .field("start_geodes_cracked", &self.start_geodes_cracked)
+            .field("slots", &self.slots)
+            .field("geodes", &self.geodes)
+            .finish()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Predictor for GeodePlan<G> {
+    fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+        for (geodes_cracked, slot) in self.geodes_cracked().zip(&self.slots) {
+            if slot.item.is_none() {
+                continue;
+            }
+            let crack = Crack::new::<G>(&self.geodes[slot.geode].1, state, geodes_cracked)?;
+            if !slot.is_met(&crack) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Scores the fraction of required items received.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        let (met, required) = self.met_slots(state)?;
+        if required == 0 {
+            return Ok(1.0);
+        }
+
+        Ok(met as f64 / required as f64)
+    }
+
+    /// Buckets seeds by the number of required items received.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let (met, required) = self.met_slots(state)?;
+        Ok((
+            met == required,
+            format!("{met} of {required} items received"),
+        ))
+    }
+
+    fn report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn std::io::prelude::Write,
+    ) -> Result<()> {
+        writeln!(writer, "Geode plan:")?;
+        for (slot, crack) in self.slots.iter().zip(self.cracks(state)?) {
+            crack.report(game_data, locale, Some(self.geodes[slot.geode].0), writer)?;
+        }
+        Ok(())
+    }
+
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let geodes = self
+            .slots
+            .iter()
+            .zip(self.cracks(state)?)
+            .map(|(slot, crack)| {
+                Ok(GeodePlanSlotReport {
+                    geode_type: self.geodes[slot.geode].0,
+                    crack: crack.report_structured(game_data, locale)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PredictorReport::GeodePlan(GeodePlanReport { geodes }))
+    }
+
+    fn explain(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let mut missing = Vec::new();
+        for (slot, crack) in self.slots.iter().zip(self.cracks(state)?) {
+            let Some(item) = &slot.item else {
+                continue;
+            };
+            if slot.is_met(&crack) {
+                continue;
+            }
+            missing.push(format!(
+                "{} {:?}: got {} {}, need {} {}",
+                crack.geodes_cracked,
+                self.geodes[slot.geode].0,
+                crack.quantity,
+                crack.item_name(game_data, locale)?,
+                slot.quantity,
+                game_data.get_object_by_id(item)?.display_name(locale)
+            ));
+        }
+        let detail = if missing.is_empty() {
+            format!("Geode plan: all {} geodes matched", self.slots.len())
+        } else {
+            format!("Geode plan: {}", missing.join("; "))
+        };
+        Ok(Explanation::new(missing.is_empty(), Some(detail)))
+    }
+}
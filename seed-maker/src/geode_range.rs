@@ -0,0 +1,224 @@
+use std::{fmt::Debug, io::Write};
+
+use schemars::JsonSchema;
+use sdv::{predictor::PredictionGameState, rng::SeedGenerator, GameData, Locale};
+use serde::{Deserialize, Serialize};
+
+use crate::{Explanation, Geode, GeodeConfig, GeodeReport, Predictor, PredictorReport, Result};
+
+/// Configuration for the [`GeodeRange`] Predictor.
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "type": "geode_range",
+///    "start_geodes_cracked": 1,
+///    "end_geodes_cracked": 200,
+///    "min_matches": 3,
+///    "child": {
+///        "item": "Prismatic Shard",
+///        "quantity": 1,
+///        "geode_type": "omni_geode"
+///    }
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct GeodeRangeConfig {
+    /// `geodes_cracked` counter of the first geode in the range (inclusive).
+    pub start_geodes_cracked: u32,
+
+    /// `geodes_cracked` counter of the last geode in the range (inclusive).
+    pub end_geodes_cracked: u32,
+
+    /// Minimum number of geodes in the range the child predictor must match.
+    pub min_matches: usize,
+
+    /// [`GeodeConfig`] of the child predictor called for every geode between
+    /// `start_geodes_cracked` and `end_geodes_cracked`.
+    pub child: GeodeConfig,
+}
+
+/// Structured report of a [`GeodeRange`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GeodeRangeReport {
+    /// Minimum number of geodes the child predictor must match.
+    pub min_matches: usize,
+
+    /// Reports of the child predictor for each geode it matched.
+    pub geodes: Vec<GeodeRangeHitReport>,
+}
+
+/// Structured report of a [`GeodeRange`] child predictor on a single geode.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GeodeRangeHitReport {
+    /// `geodes_cracked` counter of the geode.
+    pub geodes_cracked: u32,
+
+    /// Report of the child predictor.
+    pub report: GeodeReport,
+}
+
+/// Runs a child [`Geode`] predictor over a range of geodes cracked.
+///
+/// Configured through [`GeodeRangeConfig`].
+///
+/// `GeodeRange` will run its child predictor for every `geodes_cracked` value
+/// between `start_geodes_cracked` and `end_geodes_cracked` (inclusive).  If
+/// the child predictor succeeds at least `min_matches` times, `GeodeRange`
+/// will report a success.
+pub struct GeodeRange<G: Send + Sync + SeedGenerator> {
+    start_geodes_cracked: u32,
+    end_geodes_cracked: u32,
+    min_matches: usize,
+    child: Geode<G>,
+}
+
+impl<G: Send + Sync + SeedGenerator> GeodeRange<G> {
+    /// Create a new [`GeodeRange`] predictor from a [`GeodeRangeConfig`].
+    pub fn new(game_data: &GameData, locale: &Locale, config: &GeodeRangeConfig) -> Result<Self> {
+        Ok(Self {
+            start_geodes_cracked: config.start_geodes_cracked,
+            end_geodes_cracked: config.end_geodes_cracked,
+            min_matches: config.min_matches,
+            child: Geode::new(game_data, locale, &config.child)?,
+        })
+    }
+
+    /// `geodes_cracked` values in the range the child predictor matches.
+    fn matches(&self, state: &PredictionGameState) -> Result<Vec<u32>> {
+        let mut matches = Vec::new();
+        for geodes_cracked in self.start_geodes_cracked..=self.end_geodes_cracked {
+            let state = PredictionGameState {
+                geodes_cracked,
+                ..*state
+            };
+            if self.child.predict(&state)? {
+                matches.push(geodes_cracked);
+            }
+        }
+        Ok(matches)
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Debug for GeodeRange<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeodeRange")
+            .field("start_geodes_cracked", &self.start_geodes_cracked)
+            .field("end_geodes_cracked", &self.end_geodes_cracked)
+            .field("min_matches", &self.min_matches)
+            .field("child", &self.child)
+            .finish()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Predictor for GeodeRange<G> {
+    fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+        let mut sucesses = 0;
+        for geodes_cracked in self.start_geodes_cracked..=self.end_geodes_cracked {
+            if sucesses >= self.min_matches {
+                break;
+            }
+            let state = PredictionGameState {
+                geodes_cracked,
+                ..*state
+            };
+            if self.child.predict(&state)? {
+                sucesses += 1;
+            }
+        }
+        Ok(sucesses >= self.min_matches)
+    }
+
+    /// Scores the fraction of `min_matches` geodes that matched.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        if self.min_matches == 0 {
+            return Ok(1.0);
+        }
+
+        let sucesses = self.matches(state)?.len();
+        Ok((sucesses as f64 / self.min_matches as f64).min(1.0))
+    }
+
+    /// Buckets seeds by the number of geodes matched.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let matches = self.matches(state)?.len();
+        Ok((
+            matches >= self.min_matches,
+            format!("{matches} geodes matched"),
+        ))
+    }
+
+    fn report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
+        for geodes_cracked in self.matches(state)? {
+            let state = PredictionGameState {
+                geodes_cracked,
+                ..*state
+            };
+            write!(writer, "Geode {geodes_cracked} ")?;
+            self.child.report(game_data, locale, &state, writer)?;
+        }
+        Ok(())
+    }
+
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let geodes = self
+            .matches(state)?
+            .into_iter()
+            .map(|geodes_cracked| {
+                let state = PredictionGameState {
+                    geodes_cracked,
+                    ..*state
+                };
+                Ok(GeodeRangeHitReport {
+                    geodes_cracked,
+                    report: self.child.geode_report(game_data, locale, &state)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PredictorReport::GeodeRange(GeodeRangeReport {
+            min_matches: self.min_matches,
+            geodes,
+        }))
+    }
+
+    fn explain(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let matches = self.matches(state)?;
+        let detail = format!(
+            "Geodes {} - {}: {} of {} required geodes matched [{}]",
+            self.start_geodes_cracked,
+            self.end_geodes_cracked,
+            matches.len(),
+            self.min_matches,
+            matches
+                .iter()
+                .map(|geodes_cracked| geodes_cracked.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        Ok(Explanation::new(
+            matches.len() >= self.min_matches,
+            Some(detail),
+        ))
+    }
+}
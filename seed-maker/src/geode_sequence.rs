@@ -0,0 +1,306 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use anyhow::bail;
+use schemars::JsonSchema;
+use sdv::{
+    common::ItemId,
+    predictor::{self, geode::GeodeType, PredictionGameState},
+    rng::SeedGenerator,
+    GameData, Locale,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    geode::Crack, item_names::resolve_item, Explanation, GeodeCrackReport, Predictor,
+    PredictorReport, Result,
+};
+
+/// Configuration for [`GeodeSequence`].
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "type": "geode_sequence",
+///    "geode_type": "geode",
+///    "start_geodes_cracked": 1,
+///    "count": 10,
+///    "items": [
+///        { "item": "Copper Ore", "quantity": 20 },
+///        { "item": "Earth Crystal", "position": 3 }
+///    ]
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct GeodeSequenceConfig {
+    /// Type of geode cracked.
+    #[schemars(schema_with = "crate::schema::geode_type_schema")]
+    #[serde(serialize_with = "crate::config_format::serialize_variant_name")]
+    pub geode_type: GeodeType,
+
+    /// `geodes_cracked` counter of the first geode in the sequence.
+    ///
+    /// The game increments the counter before determining a geode's contents
+    /// so the first geode ever cracked is 1.  Defaults to 1.
+    #[serde(default = "crate::one")]
+    pub start_geodes_cracked: u32,
+
+    /// Number of geodes cracked in a row.
+    pub count: u32,
+
+    /// Items required from the sequence.
+    pub items: Vec<GeodeSequenceItem>,
+}
+
+/// An item required by a [`GeodeSequenceConfig`].
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct GeodeSequenceItem {
+    /// Item required.
+    ///
+    /// Either a display name like `Copper Ore` or a qualified ID like
+    /// `(O)378`.
+    pub item: String,
+
+    /// Minimum quantity required.
+    ///
+    /// Without a `position` this is the total received across the whole
+    /// sequence.  Defaults to 1.
+    #[serde(default = "crate::one")]
+    pub quantity: u32,
+
+    /// Position in the sequence, starting at 1, of the geode that must
+    /// contain the item.
+    ///
+    /// Defaults to anywhere in the sequence.
+    #[serde(default)]
+    pub position: Option<u32>,
+}
+
+/// Structured report of a [`GeodeSequence`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GeodeSequenceReport {
+    /// Type of geode cracked.
+    pub geode_type: GeodeType,
+
+    /// Contents of every geode in the sequence.
+    pub geodes: Vec<GeodeCrackReport>,
+}
+
+#[derive(Clone, Debug)]
+struct Requirement {
+    item: ItemId,
+    quantity: u32,
+    position: Option<u32>,
+}
+
+impl Requirement {
+    fn is_met(&self, cracks: &[Crack]) -> bool {
+        match self.position {
+            Some(position) => cracks
+                .get(position.saturating_sub(1) as usize)
+                .is_some_and(|crack| crack.item == self.item && crack.quantity >= self.quantity),
+            None => {
+                let total: u32 = cracks
+                    .iter()
+                    .filter(|crack| crack.item == self.item)
+                    .map(|crack| crack.quantity)
+                    .sum();
+                total >= self.quantity
+            }
+        }
+    }
+}
+
+/// Predictor for items received by cracking several geodes in a row.
+///
+/// Configured through [`GeodeSequenceConfig`].
+///
+/// Cracks `count` geodes starting at `start_geodes_cracked` and matches if
+/// every required item is received, either from the geode at its `position`
+/// or in total across the sequence.
+#[derive(Clone)]
+pub struct GeodeSequence<G: Send + Sync + SeedGenerator> {
+    start_geodes_cracked: u32,
+    count: u32,
+    requirements: Vec<Requirement>,
+    geode: predictor::geode::Geode,
+
+    // Used for reporting.
+    geode_type: GeodeType,
+
+    phantom: PhantomData<G>,
+}
+
+impl<G: Send + Sync + SeedGenerator> GeodeSequence<G> {
+    /// Create a new [`GeodeSequence`] from a [`GeodeSequenceConfig`].
+    pub fn new(
+        game_data: &GameData,
+        locale: &Locale,
+        config: &GeodeSequenceConfig,
+    ) -> Result<Self> {
+        if config
+            .start_geodes_cracked
+            .checked_add(config.count)
+            .is_none()
+        {
+            bail!(
+                "sequence of {} geodes starting at {} overflows geodes_cracked",
+                config.count,
+                config.start_geodes_cracked
+            );
+        }
+        let requirements = config
+            .items
+            .iter()
+            .map(|item| {
+                Ok(Requirement {
+                    item: resolve_item(game_data, locale, &item.item)?,
+                    quantity: item.quantity,
+                    position: item.position,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            start_geodes_cracked: config.start_geodes_cracked,
+            count: config.count,
+            requirements,
+            geode: predictor::geode::Geode::new(config.geode_type, game_data)?,
+            geode_type: config.geode_type,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Contents of every geode in the sequence.
+    fn cracks(&self, state: &PredictionGameState) -> Result<Vec<Crack>> {
+        let mut cracks = Vec::with_capacity(self.count as usize);
+        for geodes_cracked in self.start_geodes_cracked..self.start_geodes_cracked + self.count {
+            cracks.push(Crack::new::<G>(&self.geode, state, geodes_cracked)?);
+        }
+        Ok(cracks)
+    }
+
+    /// Number of requirements met.
+    fn met_requirements(&self, state: &PredictionGameState) -> Result<usize> {
+        let cracks = self.cracks(state)?;
+        Ok(self
+            .requirements
+            .iter()
+            .filter(|requirement| requirement.is_met(&cracks))
+            .count())
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Debug for GeodeSequence<G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeodeSequence")
+            .field("start_geodes_cracked", &self.start_geodes_cracked)
+            .field("count", &self.count)
+            .field("requirements", &self.requirements)
+            .field("geode", &self.geode)
+            .finish()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Predictor for GeodeSequence<G> {
+    fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+        let cracks = self.cracks(state)?;
+        Ok(self
+            .requirements
+            .iter()
+            .all(|requirement| requirement.is_met(&cracks)))
+    }
+
+    /// Scores the fraction of required items received.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        if self.requirements.is_empty() {
+            return Ok(1.0);
+        }
+
+        Ok(self.met_requirements(state)? as f64 / self.requirements.len() as f64)
+    }
+
+    /// Buckets seeds by the number of required items received.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let met = self.met_requirements(state)?;
+        Ok((
+            met == self.requirements.len(),
+            format!("{met} of {} items received", self.requirements.len()),
+        ))
+    }
+
+    fn report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn std::io::prelude::Write,
+    ) -> Result<()> {
+        writeln!(writer, "{:?} sequence:", self.geode_type)?;
+        for crack in self.cracks(state)? {
+            crack.report(game_data, locale, None, writer)?;
+        }
+        Ok(())
+    }
+
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let geodes = self
+            .cracks(state)?
+            .into_iter()
+            .map(|crack| crack.report_structured(game_data, locale))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(PredictorReport::GeodeSequence(GeodeSequenceReport {
+            geode_type: self.geode_type,
+            geodes,
+        }))
+    }
+
+    fn explain(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let cracks = self.cracks(state)?;
+        let mut missing = Vec::new();
+        for requirement in &self.requirements {
+            if requirement.is_met(&cracks) {
+                continue;
+            }
+            let name = game_data
+                .get_object_by_id(&requirement.item)?
+                .display_name(locale);
+            missing.push(match requirement.position {
+                Some(position) => format!("{} {name} at position {position}", requirement.quantity),
+                None => format!("{} {name}", requirement.quantity),
+            });
+        }
+        let last = self.start_geodes_cracked + self.count.saturating_sub(1);
+        let detail = if missing.is_empty() {
+            format!(
+                "{:?} {}-{}: all {} items received",
+                self.geode_type,
+                self.start_geodes_cracked,
+                last,
+                self.requirements.len()
+            )
+        } else {
+            format!(
+                "{:?} {}-{}: missing {}",
+                self.geode_type,
+                self.start_geodes_cracked,
+                last,
+                missing.join(", ")
+            )
+        };
+        Ok(Explanation::new(missing.is_empty(), Some(detail)))
+    }
+}
@@ -0,0 +1,80 @@
+use anyhow::bail;
+use sdv::{common::ItemId, GameData, Locale};
+
+use crate::Result;
+
+/// Minimum similarity for an item name to be suggested.
+const SUGGESTION_THRESHOLD: f64 = 0.8;
+
+/// Maximum number of suggestions included in an unknown item error.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Returns true if `name` is written like an item ID instead of a display
+/// name.
+///
+/// Qualified IDs look like `(O)378` and special IDs like `DISH_OF_THE_DAY`.
+fn is_item_id(name: &str) -> bool {
+    name.starts_with('(')
+        || name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Resolve an item reference from a config to an [`ItemId`].
+///
+/// Accepts qualified IDs like `(O)378` or display names like `Copper Ore`.
+/// Display names are matched case insensitively in `locale`.  Names shared by
+/// several items, like `Stone`, are ambiguous and produce an error listing
+/// the qualified IDs to use instead.  Unknown names produce an error
+/// suggesting similarly named items.
+pub(crate) fn resolve_item(game_data: &GameData, locale: &Locale, name: &str) -> Result<ItemId> {
+    if is_item_id(name) {
+        return name.parse();
+    }
+
+    let wanted = name.to_lowercase();
+    let mut matches = Vec::new();
+    let mut suggestions = Vec::new();
+    for (id, object) in &game_data.objects {
+        let display_name = object.display_name(locale);
+        let candidate = display_name.to_lowercase();
+        if candidate == wanted {
+            matches.push(id);
+            continue;
+        }
+
+        let similarity = strsim::jaro_winkler(&wanted, &candidate);
+        if similarity >= SUGGESTION_THRESHOLD {
+            suggestions.push((similarity, display_name));
+        }
+    }
+
+    match matches.as_slice() {
+        [] => (),
+        [id] => return Ok((*id).clone()),
+        _ => {
+            // Sort so the error doesn't depend on the object map's order.
+            let mut ids = matches.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+            ids.sort();
+            bail!(
+                "item name \"{name}\" is ambiguous, use one of {}",
+                ids.join(", ")
+            );
+        }
+    }
+
+    if suggestions.is_empty() {
+        bail!("unknown item \"{name}\"");
+    }
+    suggestions.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(b.1)));
+    suggestions.dedup_by(|a, b| a.1 == b.1);
+    let suggestions = suggestions
+        .iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| format!("\"{name}\""))
+        .collect::<Vec<_>>();
+    bail!(
+        "unknown item \"{name}\", did you mean {}?",
+        suggestions.join(" or ")
+    );
+}
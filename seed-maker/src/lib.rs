@@ -9,18 +9,32 @@
 //!   of the game from a seed and match that state against specified conditions.
 //! * **Configuration**: A [`serde`] compatible
 //!   [configuration declaration](`SeedFinderConfig`) capable of describing
-//!   seed finding conditions without writing code.
+//!   seed finding conditions without writing code.  Configs can be written in
+//!   any [`ConfigFormat`].
 //! * **Seed Finding Engine**: A high-performance, multi-threaded engine that
 //!   searches the seed space for seeds that match a given configuration.
 //!
 //! ## Predictors
 //!
+//! * [`AllOf`] / [`AllOfConfig`]: Match when all child predictors match.
+//! * [`AnyOf`] / [`AnyOfConfig`]: Match when any child predictor matches.
 //! * [`DayRange`] / [`DayRangeConfig`]: Run a child predictor over a given day
 //!   range
 //! * [`Garbage`] / [`GarbageConfig`]: Predict items from trash cans around town.
 //! * [`Geode`] / [`GeodeConfig`]: Predict items from breaking geodes.
+//! * [`GeodePlan`] / [`GeodePlanConfig`]: Predict items from breaking a mix of
+//!   geode types in order.
+//! * [`GeodeRange`] / [`GeodeRangeConfig`]: Run a child [`Geode`] predictor
+//!   over a range of geodes cracked.
+//! * [`GeodeSequence`] / [`GeodeSequenceConfig`]: Predict items from breaking
+//!   several geodes in a row.
+//! * [`MineLevel`] / [`MineLevelConfig`]: Predict mushroom, monster and slime
+//!   floors in the mines.
 //! * [`NightEvent`] / [`NightEventConfig`]: Predict night events like fairies
 //!   and meteors.
+//! * [`Not`] / [`NotConfig`]: Invert the result of a child predictor.
+//! * [`PlainStones`] / [`PlainStonesConfig`]: Predict ladders and drops from
+//!   breaking plain stones on mine floors 1 to 120.
 //! * [`Weather`] / [`WeatherConfig`]: Predict weather.
 //!
 //! ## Example
@@ -34,15 +48,14 @@
 //! let game_data = GameData::from_content_dir(get_game_content_path().unwrap())?;
 //! let locale = Locale::from_content_dir(get_game_content_path().unwrap(), "en-EN")?;
 //!
-//! // Load configuration from a JSON file using [`serde_json`]
-//! let config_data = std::fs::read_to_string("test-config.json")?;
-//! let config: SeedFinderConfig = serde_json::from_str(&config_data)?;
+//! // Load configuration from a JSON, TOML, YAML or RON file.
+//! let config = SeedFinderConfig::from_path("test-config.json")?;
 //!
 //! // Create a new seed finder from the configuration.
-//! let finder = SeedFinder::new(&game_data, &config)?;
+//! let finder = SeedFinder::new(&game_data, &locale, &config)?;
 //!
 //! // Run the seed finder.
-//! let seeds = finder.find_seeds();
+//! let seeds = finder.find_seeds()?;
 //!
 //! // Print out reports on found seeds.
 //! for seed in seeds {
@@ -60,14 +73,20 @@ use std::{
     fmt::Debug,
     io::Write,
     marker::PhantomData,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
     sync::{
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         mpsc::{self, Receiver},
-        Arc,
+        Arc, OnceLock,
     },
+    time::{Duration, Instant},
 };
 
+use anyhow::{bail, Context};
+use ranked::{ScoredSeed, TopSeeds};
 use rayon::prelude::*;
+use schemars::JsonSchema;
 use sdv::{
     predictor::PredictionGameState,
     rng::{HashedSeedGenerator, LegacySeedGenerator, SeedGenerator},
@@ -78,15 +97,56 @@ use serde::{Deserialize, Serialize};
 pub use anyhow::Result;
 pub use sdv;
 
+mod calibration;
+mod checkpoint;
+mod combinator;
+mod config_format;
+mod date;
+mod estimate;
 mod garbage;
 mod geode;
+mod geode_plan;
+mod geode_range;
+mod geode_sequence;
+mod item_names;
+mod mine_level;
 mod night_event;
+mod plain_stones;
+mod ranked;
+mod schema;
+mod statistics;
+mod validate;
 mod weather;
 
-pub use garbage::{Garbage, GarbageConfig};
-pub use geode::{Geode, GeodeConfig};
-pub use night_event::{NightEvent, NightEventConfig};
-pub use weather::{Weather, WeatherConfig};
+pub use calibration::{Calibration, PredictorStats};
+pub use checkpoint::Checkpoint;
+pub use combinator::{
+    AllOf, AllOfConfig, AnyOf, AnyOfConfig, BranchReport, BranchesReport, Not, NotConfig, NotReport,
+};
+pub use config_format::ConfigFormat;
+pub use date::{Date, Day, Season};
+pub use estimate::{Estimate, Interval, PredictorEstimate};
+pub use garbage::{Garbage, GarbageCanReport, GarbageConfig, GarbageReport};
+pub use geode::{Geode, GeodeConfig, GeodeCrackReport, GeodeReport};
+pub use geode_plan::{
+    GeodePlan, GeodePlanConfig, GeodePlanReport, GeodePlanSlot, GeodePlanSlotReport,
+};
+pub use geode_range::{GeodeRange, GeodeRangeConfig, GeodeRangeHitReport, GeodeRangeReport};
+pub use geode_sequence::{
+    GeodeSequence, GeodeSequenceConfig, GeodeSequenceItem, GeodeSequenceReport,
+};
+pub use mine_level::{
+    FloorType, MineFloorConfig, MineFloorReport, MineLevel, MineLevelConfig, MineLevelReport,
+};
+pub use night_event::{NightEvent, NightEventConfig, NightEventReport};
+pub use plain_stones::{
+    MineTile, PlainStoneReport, PlainStones, PlainStonesConfig, PlainStonesReport, StoneDrop,
+    StoneDropConfig,
+};
+pub use schema::config_schema;
+pub use statistics::{Bucket, Histogram, Statistics};
+pub use validate::ValidationError;
+pub use weather::{Weather, WeatherConfig, WeatherReport};
 
 /// A trait describing a specific seed finding predictor
 pub trait Predictor: Send + Sync + core::fmt::Debug {
@@ -106,6 +166,82 @@ pub trait Predictor: Send + Sync + core::fmt::Debug {
         state: &PredictionGameState,
         writer: &mut dyn Write,
     ) -> Result<()>;
+
+    /// Generate a structured report for a seed.
+    ///
+    /// Contains the same information as [`Predictor::report`] in a form
+    /// that can be serialized.
+    fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport>;
+
+    /// Score how close a seed comes to matching.
+    ///
+    /// Returns a value between 0.0 and 1.0 where 1.0 is a full match.  The
+    /// default implementation returns 1.0 if [`Predictor::predict`] matches
+    /// and 0.0 otherwise.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        Ok(if self.predict(state)? { 1.0 } else { 0.0 })
+    }
+
+    /// Describe the outcome of a seed for [`SeedFinder::statistics`].
+    ///
+    /// Returns the same result as [`Predictor::predict`] along with the
+    /// outcome so each seed only has to be predicted once.  Seeds with equal
+    /// outcomes are counted in the same histogram bucket.  The default
+    /// implementation describes whether [`Predictor::predict`] matches.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let matched = self.predict(state)?;
+        let outcome = if matched { "matched" } else { "not matched" };
+        Ok((matched, outcome.to_string()))
+    }
+
+    /// Explain why a seed does or doesn't match.
+    ///
+    /// The default implementation only records the result of
+    /// [`Predictor::predict`].
+    fn explain(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        Ok(Explanation::new(self.predict(state)?, None))
+    }
+}
+
+/// Explanation of a [`Predictor`]'s result for a seed.
+///
+/// Returned by [`Predictor::explain`] and [`SeedFinder::explain`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Explanation {
+    /// True if the predictor matched.
+    pub matched: bool,
+
+    /// Human readable details of the result, if available.
+    pub detail: Option<String>,
+
+    /// Explanations of child predictors.
+    pub children: Vec<Explanation>,
+}
+
+impl Explanation {
+    /// Create a new [`Explanation`] with no children.
+    pub fn new(matched: bool, detail: Option<String>) -> Self {
+        Self {
+            matched,
+            detail,
+            children: Vec::new(),
+        }
+    }
 }
 
 /// Configuration for the [`DayRange`] Predictor.
@@ -115,7 +251,7 @@ pub trait Predictor: Send + Sync + core::fmt::Debug {
 /// {
 ///    "type": "day_range",
 ///    "start_day": 1,
-///    "end_day": 12,
+///    "end_day": "Y1 Spring 12",
 ///    "min_matches": 4,
 ///    "child": {
 ///        "type": "weather",
@@ -123,13 +259,13 @@ pub trait Predictor: Send + Sync + core::fmt::Debug {
 ///    }
 /// }
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
 pub struct DayRangeConfig {
     /// Starting day of the range (inclusive).
-    pub start_day: u32,
+    pub start_day: Day,
 
     /// Ending day of the range (inclusive).
-    pub end_day: u32,
+    pub end_day: Day,
 
     /// Minimum number of successful days of the child predictor needed.
     pub min_matches: usize,
@@ -139,6 +275,29 @@ pub struct DayRangeConfig {
     pub child: Box<PredictorConfig>,
 }
 
+/// Structured report of a [`DayRange`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DayRangeReport {
+    /// Minimum number of successful days of the child predictor needed.
+    pub min_matches: usize,
+
+    /// Reports of the child predictor for each day it succeeded.
+    pub days: Vec<DayReport>,
+}
+
+/// Structured report of a [`DayRange`] child predictor on a single day.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DayReport {
+    /// Day of the report as a `days_played` counter.
+    pub day: u32,
+
+    /// Day of the report as a calendar date.
+    pub date: Date,
+
+    /// Report of the child predictor.
+    pub report: PredictorReport,
+}
+
 /// Runs a child [`Predictor`] over a range of days.
 ///
 /// Configured through [`DayRangeConfig`].
@@ -156,11 +315,11 @@ pub struct DayRange<G: Sync + SeedGenerator> {
 
 impl<G: 'static + Send + Sync + SeedGenerator> DayRange<G> {
     /// Create a new [`DayRange`] predictor from a [`DayRangeConfig`].
-    pub fn new(game_data: &GameData, config: &DayRangeConfig) -> Result<Self> {
-        let child = config.child.predictor::<G>(game_data)?;
+    pub fn new(game_data: &GameData, locale: &Locale, config: &DayRangeConfig) -> Result<Self> {
+        let child = config.child.predictor::<G>(game_data, locale)?;
         Ok(Self {
-            start_day: config.start_day,
-            end_day: config.end_day,
+            start_day: config.start_day.days_played(),
+            end_day: config.end_day.days_played(),
             min_matches: config.min_matches,
             child,
             phantom: PhantomData,
@@ -168,6 +327,23 @@ impl<G: 'static + Send + Sync + SeedGenerator> DayRange<G> {
     }
 }
 
+impl<G: Send + Sync + SeedGenerator> DayRange<G> {
+    /// Number of days in the range the child predictor matches.
+    fn count_matches(&self, state: &PredictionGameState) -> Result<usize> {
+        let mut sucesses = 0;
+        for day in self.start_day..=self.end_day {
+            let state = PredictionGameState {
+                days_played: day,
+                ..*state
+            };
+            if self.child.predict(&state)? {
+                sucesses += 1;
+            }
+        }
+        Ok(sucesses)
+    }
+}
+
 impl<G: Send + Sync + SeedGenerator> Debug for DayRange<G> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DayRange")
@@ -181,39 +357,107 @@ impl<G: Send + Sync + SeedGenerator> Debug for DayRange<G> {
 
 impl<G: Send + Sync + SeedGenerator> Predictor for DayRange<G> {
     fn predict(&self, state: &PredictionGameState) -> Result<bool> {
-        let mut sucesses = 0;
+        Ok(self.count_matches(state)? >= self.min_matches)
+    }
 
+    /// Scores the fraction of `min_matches` days that matched.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        if self.min_matches == 0 {
+            return Ok(1.0);
+        }
+
+        let sucesses = self.count_matches(state)?;
+        Ok((sucesses as f64 / self.min_matches as f64).min(1.0))
+    }
+
+    /// Buckets seeds by the number of days matched.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let matches = self.count_matches(state)?;
+        Ok((
+            matches >= self.min_matches,
+            format!("{matches} days matched"),
+        ))
+    }
+
+    fn report(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn Write,
+    ) -> Result<()> {
         for day in self.start_day..=self.end_day {
             let state = PredictionGameState {
                 days_played: day,
                 ..*state
             };
             if self.child.predict(&state)? {
-                sucesses += 1;
+                write!(writer, "{} ", Date::from_days_played(day))?;
+                self.child.report(game_data, locale, &state, writer)?;
             }
         }
-
-        Ok(sucesses >= self.min_matches)
+        Ok(())
     }
 
-    fn report(
+    fn report_structured(
         &self,
         game_data: &GameData,
         locale: &Locale,
         state: &PredictionGameState,
-        writer: &mut dyn Write,
-    ) -> Result<()> {
+    ) -> Result<PredictorReport> {
+        let mut days = Vec::new();
         for day in self.start_day..=self.end_day {
             let state = PredictionGameState {
                 days_played: day,
                 ..*state
             };
             if self.child.predict(&state)? {
-                write!(writer, "Day {day} ")?;
-                self.child.report(game_data, locale, &state, writer)?;
+                days.push(DayReport {
+                    day,
+                    date: Date::from_days_played(day),
+                    report: self.child.report_structured(game_data, locale, &state)?,
+                });
             }
         }
-        Ok(())
+        Ok(PredictorReport::DayRange(DayRangeReport {
+            min_matches: self.min_matches,
+            days,
+        }))
+    }
+
+    fn explain(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let mut days = Vec::new();
+        for day in self.start_day..=self.end_day {
+            let state = PredictionGameState {
+                days_played: day,
+                ..*state
+            };
+            if self.child.predict(&state)? {
+                days.push(Date::from_days_played(day).to_string());
+            }
+        }
+        let detail = format!(
+            "{} - {}: {} of {} required days matched [{}]",
+            Date::from_days_played(self.start_day),
+            Date::from_days_played(self.end_day),
+            days.len(),
+            self.min_matches,
+            days.join(", ")
+        );
+        Ok(Explanation::new(
+            days.len() >= self.min_matches,
+            Some(detail),
+        ))
     }
 }
 
@@ -221,8 +465,16 @@ fn one() -> u32 {
     1
 }
 
+fn yes() -> bool {
+    true
+}
+
+fn max_seed() -> i32 {
+    i32::MAX
+}
+
 /// Type of RNG seeding used.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RngType {
     /// Default 1.6 RNG seeding.
@@ -242,7 +494,7 @@ impl Default for RngType {
 ///
 /// Used in [`SeedFinderConfig`] to set the initial state of the configured
 /// predictors.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
 pub struct SeedFinderStateConfig {
     /// Mutiplayer ID.
     ///
@@ -255,9 +507,8 @@ pub struct SeedFinderStateConfig {
 
     /// Day of interest.
     ///
-    /// Starts as 1 on Year 1, Spring 1.  Increments every day.  Does not reset
-    /// at the begining of month or year.
-    pub day: u32,
+    /// Either a `days_played` counter or a calendar [`Date`].  See [`Day`].
+    pub day: Day,
 
     /// Daily Luck.
     ///
@@ -281,7 +532,7 @@ impl From<SeedFinderStateConfig> for PredictionGameState {
     fn from(config: SeedFinderStateConfig) -> Self {
         PredictionGameState {
             multiplayer_id: config.multiplayer_id,
-            days_played: config.day,
+            days_played: config.day.days_played(),
             daily_luck: config.daily_luck,
             geodes_cracked: config.geodes_cracked,
             deepest_mine_level: config.deepest_mine_level,
@@ -302,9 +553,18 @@ impl From<SeedFinderStateConfig> for PredictionGameState {
 ///     "geode_type": "geode"
 /// }
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize)]
+///
+/// The `type` name of a config is available through [`strum::IntoStaticStr`].
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize, strum::IntoStaticStr)]
 #[serde(tag = "type", rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum PredictorConfig {
+    /// An [`AllOf`] predictor.
+    AllOf(AllOfConfig),
+
+    /// An [`AnyOf`] predictor.
+    AnyOf(AnyOfConfig),
+
     /// A [`DayRange`] predictor.
     DayRange(DayRangeConfig),
 
@@ -314,9 +574,27 @@ pub enum PredictorConfig {
     /// A [`Geode`] predictor.
     Geode(GeodeConfig),
 
+    /// A [`GeodePlan`] predictor.
+    GeodePlan(GeodePlanConfig),
+
+    /// A [`GeodeRange`] predictor.
+    GeodeRange(GeodeRangeConfig),
+
+    /// A [`GeodeSequence`] predictor.
+    GeodeSequence(GeodeSequenceConfig),
+
+    /// A [`MineLevel`] predictor.
+    MineLevel(MineLevelConfig),
+
     /// A [`NightEvent`] predictor.
     NightEvent(NightEventConfig),
 
+    /// A [`Not`] predictor.
+    Not(NotConfig),
+
+    /// A [`PlainStones`] predictor.
+    PlainStones(PlainStonesConfig),
+
     /// A [`Weather`] predictor.
     Weather(WeatherConfig),
 }
@@ -324,28 +602,62 @@ pub enum PredictorConfig {
 impl PredictorConfig {
     /// Create a new [`Predictor`] using this configuration.
     ///
-    /// Returns a `Box<dyn Predictor>` of the new predictor.
+    /// Returns a `Box<dyn Predictor>` of the new predictor.  Item names are
+    /// resolved using `locale`.
     pub fn predictor<G: 'static + Send + Sync + SeedGenerator>(
         &self,
         game_data: &GameData,
+        locale: &Locale,
     ) -> Result<Box<dyn Predictor>> {
         match self {
+            PredictorConfig::AllOf(config) => {
+                let p = AllOf::<G>::new(game_data, locale, config)?;
+                Ok(Box::new(p))
+            }
+            PredictorConfig::AnyOf(config) => {
+                let p = AnyOf::<G>::new(game_data, locale, config)?;
+                Ok(Box::new(p))
+            }
             PredictorConfig::DayRange(config) => {
-                let p = DayRange::<G>::new(game_data, config)?;
+                let p = DayRange::<G>::new(game_data, locale, config)?;
                 Ok(Box::new(p))
             }
             PredictorConfig::Garbage(config) => {
-                let p = Garbage::<G>::new(game_data, config)?;
+                let p = Garbage::<G>::new(game_data, locale, config)?;
                 Ok(Box::new(p))
             }
             PredictorConfig::Geode(config) => {
-                let p = Geode::<G>::new(game_data, config)?;
+                let p = Geode::<G>::new(game_data, locale, config)?;
+                Ok(Box::new(p))
+            }
+            PredictorConfig::GeodePlan(config) => {
+                let p = GeodePlan::<G>::new(game_data, locale, config)?;
+                Ok(Box::new(p))
+            }
+            PredictorConfig::GeodeRange(config) => {
+                let p = GeodeRange::<G>::new(game_data, locale, config)?;
+                Ok(Box::new(p))
+            }
+            PredictorConfig::GeodeSequence(config) => {
+                let p = GeodeSequence::<G>::new(game_data, locale, config)?;
+                Ok(Box::new(p))
+            }
+            PredictorConfig::MineLevel(config) => {
+                let p = MineLevel::<G>::new(config)?;
                 Ok(Box::new(p))
             }
             PredictorConfig::NightEvent(config) => {
                 let p = NightEvent::<G>::new(config)?;
                 Ok(Box::new(p))
             }
+            PredictorConfig::Not(config) => {
+                let p = Not::<G>::new(game_data, locale, config)?;
+                Ok(Box::new(p))
+            }
+            PredictorConfig::PlainStones(config) => {
+                let p = PlainStones::<G>::new(config)?;
+                Ok(Box::new(p))
+            }
             PredictorConfig::Weather(config) => {
                 let p = Weather::<G>::new(game_data, config)?;
                 Ok(Box::new(p))
@@ -354,8 +666,75 @@ impl PredictorConfig {
     }
 }
 
-/// Top level configuration for as [`SeedFinder`].
+/// Structured report of a single predictor.
+///
+/// Uses `#[serde(tag = "type")]` so a JSON [`GeodeReport`] would look like:
+///
+/// ```text
+/// {
+///     "type": "geode",
+///     "geode_type": "geode",
+///     "item": "Copper Ore",
+///     "quantity": 20
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PredictorReport {
+    /// Report of an [`AllOf`] predictor.
+    AllOf(BranchesReport),
+
+    /// Report of an [`AnyOf`] predictor.
+    AnyOf(BranchesReport),
+
+    /// Report of a [`DayRange`] predictor.
+    DayRange(DayRangeReport),
+
+    /// Report of a [`Garbage`] predictor.
+    Garbage(GarbageReport),
+
+    /// Report of a [`Geode`] predictor.
+    Geode(GeodeReport),
+
+    /// Report of a [`GeodePlan`] predictor.
+    GeodePlan(GeodePlanReport),
+
+    /// Report of a [`GeodeRange`] predictor.
+    GeodeRange(GeodeRangeReport),
+
+    /// Report of a [`GeodeSequence`] predictor.
+    GeodeSequence(GeodeSequenceReport),
+
+    /// Report of a [`MineLevel`] predictor.
+    MineLevel(MineLevelReport),
+
+    /// Report of a [`NightEvent`] predictor.
+    NightEvent(NightEventReport),
+
+    /// Report of a [`Not`] predictor.
+    Not(NotReport),
+
+    /// Report of a [`PlainStones`] predictor.
+    PlainStones(PlainStonesReport),
+
+    /// Report of a [`Weather`] predictor.
+    Weather(WeatherReport),
+}
+
+/// Structured report for a seed.
+///
+/// Returned by [`SeedFinder::report_structured`].
 #[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SeedReport {
+    /// Seed the report was generated for.
+    pub seed: i32,
+
+    /// Reports for each of the configured predictors.
+    pub predictors: Vec<PredictorReport>,
+}
+
+/// Top level configuration for as [`SeedFinder`].
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
 pub struct SeedFinderConfig {
     #[serde(default)]
     /// Type of RNG used in this seed finding.
@@ -364,6 +743,50 @@ pub struct SeedFinderConfig {
     /// Maximum number of seeds to find.
     pub max_seeds: usize,
 
+    /// First seed to search (inclusive).
+    ///
+    /// Defaults to 0.
+    #[serde(default)]
+    pub seed_start: i32,
+
+    /// Last seed to search (inclusive).
+    ///
+    /// Defaults to `i32::MAX`.
+    #[serde(default = "max_seed")]
+    pub seed_end: i32,
+
+    /// Set to true to search every `i32` seed, including negative ones.
+    ///
+    /// Overrides `seed_start` and `seed_end`.  Defaults to false.
+    #[serde(default)]
+    pub full_range: bool,
+
+    /// Set to true to return the lowest `max_seeds` matching seeds in
+    /// ascending order.
+    ///
+    /// The seed range is scanned in order in chunks which is slightly slower
+    /// than the default search which returns whichever matching seeds are
+    /// found first.  Defaults to false.
+    #[serde(default)]
+    pub deterministic: bool,
+
+    /// Set to true to return the `max_seeds` highest scoring seeds, best
+    /// first, even if they don't match every predictor.
+    ///
+    /// A seed's score is the mean of each predictor's [`Predictor::score`].
+    /// Ranked searches always scan the whole seed range and don't support
+    /// checkpointing.  Defaults to false.
+    #[serde(default)]
+    pub ranked: bool,
+
+    /// Set to false to always evaluate predictors in configuration order.
+    ///
+    /// By default a few thousand seeds are sampled before searching to
+    /// measure each predictor's cost and pass rate so cheap, selective
+    /// predictors can be evaluated first.  See [`SeedFinder::calibrate`].
+    #[serde(default = "yes")]
+    pub calibrate: bool,
+
     /// Intial game state for seed finding.
     pub game_state: SeedFinderStateConfig,
 
@@ -371,12 +794,51 @@ pub struct SeedFinderConfig {
     pub predictors: Vec<PredictorConfig>,
 }
 
+impl SeedFinderConfig {
+    /// Load a config from a file.
+    ///
+    /// The format is picked from the file's extension, see [`ConfigFormat`].
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let format = ConfigFormat::from_path(path)?;
+        let data = std::fs::read_to_string(path)
+            .with_context(|| format!("can't read config {}", path.display()))?;
+        format
+            .parse(&data)
+            .with_context(|| format!("can't parse config {}", path.display()))
+    }
+
+    /// Check the config for problems that deserializing can't catch.
+    ///
+    /// Returns every problem found along with the JSON path of the offending
+    /// field.  The config is valid if every problem is a
+    /// [warning](ValidationError::warning).  Item names are resolved using
+    /// `game_data` and `locale`.
+    pub fn validate(&self, game_data: &GameData, locale: &Locale) -> Vec<ValidationError> {
+        validate::validate(self, game_data, locale)
+    }
+}
+
+/// Number of seeds scanned at a time by a deterministic or checkpointed search.
+const CHUNK_SIZE: i64 = 1 << 20;
+
+/// Minimum time between checkpoint writes.
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(30);
+
 /// Core seed finding object.
 #[derive(Debug)]
 pub struct SeedFinder {
     max_seeds: usize,
+    seed_range: RangeInclusive<i32>,
+    deterministic: bool,
+    ranked: bool,
+    calibrate: bool,
+    calibration: OnceLock<Calibration>,
     initial_state: PredictionGameState,
     predictors: Vec<Box<dyn Predictor>>,
+    config_hash: u64,
+    checkpoint_path: Option<PathBuf>,
+    resume_checkpoint: Option<Checkpoint>,
 }
 
 /// Progress Event.
@@ -385,102 +847,513 @@ pub enum Progress {
     /// A report of number of seeds searched.
     Progress(usize),
 
+    /// A seed that matches all predictors was found.
+    ///
+    /// Every seed reported here will also be included in the final
+    /// [`Progress::Complete`] or [`Progress::Cancelled`] results.
+    Found(i32),
+
+    /// A seed and its score from a `ranked` search.
+    ///
+    /// Ranked seeds are the highest scoring seeds and may not match every
+    /// predictor.  They are reported best first once the whole range has been
+    /// scanned and are also included in the final [`Progress::Complete`] or
+    /// [`Progress::Cancelled`] results.
+    Ranked(i32, f64),
+
     /// The results of a compleated search.
     Complete(Vec<i32>),
+
+    /// A predictor returned an error and the search was stopped.
+    Error(anyhow::Error),
+
+    /// The search was cancelled.  Contains the seeds found before the search
+    /// stopped.
+    Cancelled(Vec<i32>),
+}
+
+/// Handle used to cancel a search started with [`SeedFinder::find_seeds_async`].
+#[derive(Clone, Debug, Default)]
+pub struct CancelHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelHandle {
+    /// Stop the search.
+    ///
+    /// The search will stop promptly and deliver a [`Progress::Cancelled`]
+    /// event with the seeds found so far.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if the search has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
 }
 
 impl SeedFinder {
     /// Create a new `SeedFinder`
-    pub fn new(game_data: &GameData, config: &SeedFinderConfig) -> Result<Self> {
+    ///
+    /// Item names in `config` are resolved using `locale`.  Returns an error
+    /// listing every problem found by [`SeedFinderConfig::validate`] if the
+    /// config is invalid.  Warnings are ignored.
+    pub fn new(game_data: &GameData, locale: &Locale, config: &SeedFinderConfig) -> Result<Self> {
+        let errors = config
+            .validate(game_data, locale)
+            .into_iter()
+            .filter(|error| !error.warning)
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            let errors = errors
+                .iter()
+                .map(|error| format!("  {error}"))
+                .collect::<Vec<_>>();
+            bail!("invalid config:\n{}", errors.join("\n"));
+        }
+
+        let seed_range = if config.full_range {
+            i32::MIN..=i32::MAX
+        } else {
+            config.seed_start..=config.seed_end
+        };
+
         let initial_state = config.game_state.clone().into();
         let predictors = match config.rng_type {
             RngType::Hashed => config
                 .predictors
                 .iter()
-                .map(|config| config.predictor::<HashedSeedGenerator>(game_data))
+                .map(|config| config.predictor::<HashedSeedGenerator>(game_data, locale))
                 .collect::<Result<Vec<_>>>()?,
             RngType::Legacy => config
                 .predictors
                 .iter()
-                .map(|config| config.predictor::<LegacySeedGenerator>(game_data))
+                .map(|config| config.predictor::<LegacySeedGenerator>(game_data, locale))
                 .collect::<Result<Vec<_>>>()?,
         };
 
         Ok(Self {
             max_seeds: config.max_seeds,
+            seed_range,
+            deterministic: config.deterministic,
+            ranked: config.ranked,
+            calibrate: config.calibrate,
+            calibration: OnceLock::new(),
             initial_state,
             predictors,
+            config_hash: checkpoint::config_hash(config)?,
+            checkpoint_path: None,
+            resume_checkpoint: None,
         })
     }
 
-    /// Find seeds synchronously
-    pub fn find_seeds(&self) -> Vec<i32> {
-        (0..i32::MAX)
-            .into_par_iter()
-            .filter(|seed| {
-                let state = PredictionGameState {
-                    game_id: *seed as u32,
-                    ..self.initial_state
-                };
+    /// Periodically save the state of searches to `path`.
+    ///
+    /// A [`Checkpoint`] is written at most every 30 seconds while searching
+    /// and once more when the search finishes or is cancelled.  Checkpointed
+    /// searches scan the seed range in order like `deterministic` searches.
+    pub fn checkpoint_to(&mut self, path: impl Into<PathBuf>) {
+        self.checkpoint_path = Some(path.into());
+    }
 
-                for predictor in &self.predictors {
-                    if !predictor.predict(&state).unwrap() {
-                        return false;
-                    }
-                }
-                true
-            })
-            .take_any(self.max_seeds)
-            .collect()
+    /// Resume searching from the [`Checkpoint`] saved at `path`.
+    ///
+    /// Returns an error if the checkpoint was written by a search with a
+    /// different RNG type, seed range, game state or predictors.  Other
+    /// options, like `max_seeds`, can change between runs.  Seeds found before
+    /// the checkpoint are included in the results of the next search.
+    pub fn resume_from(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let checkpoint = Checkpoint::load(path)?;
+        if checkpoint.config_hash != self.config_hash {
+            bail!(
+                "checkpoint {} was created with a different config",
+                path.display()
+            );
+        }
+        self.resume_checkpoint = Some(checkpoint);
+        Ok(())
+    }
+
+    /// First seed that still needs to be searched.
+    fn search_start(&self) -> i64 {
+        match &self.resume_checkpoint {
+            Some(checkpoint) => checkpoint.next_seed,
+            None => *self.seed_range.start() as i64,
+        }
+    }
+
+    fn save_checkpoint(&self, path: &Path, next_seed: i64, seeds: &[i32]) -> Result<()> {
+        Checkpoint {
+            config_hash: self.config_hash,
+            next_seed,
+            seeds: seeds.to_vec(),
+        }
+        .save(path)
+    }
+
+    /// Number of seeds in the configured search range.
+    pub fn seed_count(&self) -> u64 {
+        (*self.seed_range.end() as i64 - *self.seed_range.start() as i64) as u64 + 1
+    }
+
+    /// Find seeds synchronously
+    ///
+    /// Returns an error if any predictor fails.  The search is stopped at the
+    /// first error.
+    pub fn find_seeds(&self) -> Result<Vec<i32>> {
+        self.search(&CancelHandle::default(), |_| (), |_| ())
     }
 
     /// Asynchronously find seeds
     ///
     /// Runs a seed search in the background while delivering progress and the
     /// eventual restults through the returned `Receiver<Progress>` channel.
+    /// The search can be stopped early with the returned [`CancelHandle`].
+    /// Dropping the `Receiver<Progress>` will also stop the search.
     ///
     /// Note: This does not use Futures or async/await.
-    pub fn find_seeds_async(finder: Arc<Self>, steps: usize) -> Receiver<Progress> {
-        let seeds_processed = Arc::new(AtomicUsize::new(0));
-        let step_size = i32::MAX as usize / steps;
+    pub fn find_seeds_async(finder: Arc<Self>, steps: usize) -> (Receiver<Progress>, CancelHandle) {
+        let cancel = CancelHandle::default();
+        let range_start = *finder.seed_range.start() as i64;
+        let seeds_processed = Arc::new(AtomicUsize::new(
+            (finder.search_start() - range_start) as usize,
+        ));
+        let step_size = (finder.seed_count() / steps as u64).max(1);
 
-        let range = 0..i32::MAX;
         let (tx, rx) = mpsc::channel();
 
         rayon::spawn({
+            let cancel = cancel.clone();
             move || {
-                let seeds = range
-                    .into_par_iter()
-                    .filter(|seed| {
+                let seeds = finder.search(
+                    &cancel,
+                    |seed| {
                         // Looking directly at the seed to tell if we've crossed as
                         // progress step boundary can yield bursty progress result
                         // however incrementing the counter every seed for accureate
                         // step counting add significant overhead (~10s).  With
                         // 1000 steps the progress updates appar smooth and don't
                         // introduce significant overhead.
-                        if *seed as usize % step_size == 0 {
-                            let cur = seeds_processed.fetch_add(step_size, Ordering::Relaxed) + 1;
-                            let _ = tx.send(Progress::Progress(cur));
-                        }
-
-                        let state = PredictionGameState {
-                            game_id: *seed as u32,
-                            ..finder.initial_state
-                        };
-
-                        for predictor in &finder.predictors {
-                            if !predictor.predict(&state).unwrap() {
-                                return false;
+                        if ((seed as i64 - range_start) as u64).is_multiple_of(step_size) {
+                            let cur = seeds_processed
+                                .fetch_add(step_size as usize, Ordering::Relaxed)
+                                + 1;
+                            if tx.send(Progress::Progress(cur)).is_err() {
+                                // Nobody is listening anymore.
+                                cancel.cancel();
                             }
                         }
-                        true
-                    })
-                    .take_any(finder.max_seeds)
-                    .collect();
-                let _ = tx.send(Progress::Complete(seeds));
+                    },
+                    |event| {
+                        let _ = tx.send(event);
+                    },
+                );
+                let _ = match seeds {
+                    Ok(seeds) if cancel.is_cancelled() => tx.send(Progress::Cancelled(seeds)),
+                    Ok(seeds) => tx.send(Progress::Complete(seeds)),
+                    Err(e) => tx.send(Progress::Error(e)),
+                };
             }
         });
-        rx
+        (rx, cancel)
+    }
+
+    /// Search the seed range.
+    ///
+    /// Honors `max_seeds`, `deterministic`, `ranked` and checkpointing.  Stops
+    /// early, returning the seeds found so far, if `cancel` is cancelled.
+    /// `progress` is called for each seed before it is checked. `found` is
+    /// called with a [`Progress::Found`], or [`Progress::Ranked`] in ranked
+    /// searches, for each seed as soon as it is known to be part of the
+    /// results.
+    fn search<P, N>(&self, cancel: &CancelHandle, progress: P, found: N) -> Result<Vec<i32>>
+    where
+        P: Fn(i32) + Send + Sync,
+        N: Fn(Progress) + Send + Sync,
+    {
+        if self.ranked {
+            return self.search_ranked(cancel, progress, found);
+        }
+
+        let order = self.predictor_order()?;
+        let filter = |seed| {
+            progress(seed);
+            self.check_seed(seed, &order)
+        };
+        let chunked = self.deterministic
+            || self.checkpoint_path.is_some()
+            || self.resume_checkpoint.is_some();
+        if !chunked {
+            return self
+                .seed_range
+                .clone()
+                .into_par_iter()
+                .take_any_while(|_| !cancel.is_cancelled())
+                .filter_map(filter)
+                .take_any(self.max_seeds)
+                .inspect(|seed| {
+                    if let Ok(seed) = seed {
+                        found(Progress::Found(*seed));
+                    }
+                })
+                .collect();
+        }
+
+        // Scan the range in order one chunk at a time.  `collect()` preserves
+        // the order of the seeds in each chunk so the results are sorted and
+        // we can stop as soon as we have enough.  Seeds are only reported
+        // once their chunk is done as earlier seeds may still be pending.
+        //
+        // `seeds` keeps every match in the scanned chunks, even past
+        // `max_seeds`, so the checkpoint never loses seeds a later run with a
+        // larger `max_seeds` would return.
+        let mut seeds = Vec::new();
+        if let Some(checkpoint) = &self.resume_checkpoint {
+            seeds.extend_from_slice(&checkpoint.seeds);
+        }
+        for &seed in seeds.iter().take(self.max_seeds) {
+            found(Progress::Found(seed));
+        }
+
+        // Every seed before `next_seed` has been searched and the first
+        // `scanned_seeds` of `seeds` were found in that part of the range.
+        let mut next_seed = self.search_start();
+        let mut scanned_seeds = seeds.len();
+        let mut last_checkpoint = Instant::now();
+        let end = *self.seed_range.end() as i64;
+        while next_seed <= end && seeds.len() < self.max_seeds && !cancel.is_cancelled() {
+            let chunk_end = (next_seed + CHUNK_SIZE - 1).min(end);
+            let chunk_seeds = (next_seed as i32..=chunk_end as i32)
+                .into_par_iter()
+                .take_any_while(|_| !cancel.is_cancelled())
+                .filter_map(&filter)
+                .collect::<Result<Vec<_>>>()?;
+            let remaining = self.max_seeds - seeds.len();
+            for &seed in chunk_seeds.iter().take(remaining) {
+                found(Progress::Found(seed));
+            }
+            seeds.extend(chunk_seeds);
+
+            // A cancelled chunk may not have been completely searched.
+            if cancel.is_cancelled() {
+                break;
+            }
+            next_seed = chunk_end + 1;
+            scanned_seeds = seeds.len();
+
+            if let Some(path) = &self.checkpoint_path {
+                if last_checkpoint.elapsed() >= CHECKPOINT_INTERVAL {
+                    self.save_checkpoint(path, next_seed, &seeds)?;
+                    last_checkpoint = Instant::now();
+                }
+            }
+        }
+
+        if let Some(path) = &self.checkpoint_path {
+            self.save_checkpoint(path, next_seed, &seeds[..scanned_seeds])?;
+        }
+        seeds.truncate(self.max_seeds);
+        Ok(seeds)
+    }
+
+    /// Search the whole seed range for the highest scoring seeds.
+    fn search_ranked<P, N>(&self, cancel: &CancelHandle, progress: P, found: N) -> Result<Vec<i32>>
+    where
+        P: Fn(i32) + Send + Sync,
+        N: Fn(Progress) + Send + Sync,
+    {
+        if self.checkpoint_path.is_some() || self.resume_checkpoint.is_some() {
+            bail!("checkpointing is not supported for ranked searches");
+        }
+
+        let top = self
+            .seed_range
+            .clone()
+            .into_par_iter()
+            .take_any_while(|_| !cancel.is_cancelled())
+            .map(|seed| {
+                progress(seed);
+                Ok(ScoredSeed {
+                    score: self.score(seed)?,
+                    seed,
+                })
+            })
+            .try_fold(
+                || TopSeeds::new(self.max_seeds),
+                |mut top, scored: Result<ScoredSeed>| {
+                    top.push(scored?);
+                    Ok::<_, anyhow::Error>(top)
+                },
+            )
+            .try_reduce(|| TopSeeds::new(self.max_seeds), |a, b| Ok(a.merge(b)))?;
+
+        let top = top.into_sorted_vec();
+        for scored in &top {
+            found(Progress::Ranked(scored.seed, scored.score));
+        }
+        Ok(top.into_iter().map(|scored| scored.seed).collect())
+    }
+
+    /// Score how close `seed` comes to matching all predictors.
+    ///
+    /// Returns the mean of each predictor's [`Predictor::score`] between 0.0
+    /// and 1.0.  A seed that matches every predictor scores 1.0.
+    pub fn score(&self, seed: i32) -> Result<f64> {
+        if self.predictors.is_empty() {
+            return Ok(1.0);
+        }
+
+        let state = PredictionGameState {
+            game_id: seed as u32,
+            ..self.initial_state
+        };
+
+        let mut total = 0.0;
+        for (i, predictor) in self.predictors.iter().enumerate() {
+            total += predictor
+                .score(&state)
+                .with_context(|| format!("predictors[{i}] failed on seed {seed}"))?;
+        }
+        Ok(total / self.predictors.len() as f64)
+    }
+
+    /// Measure the cost and pass rate of each predictor.
+    ///
+    /// Samples seeds evenly from the search range and picks the order that
+    /// minimizes the expected cost of checking a seed.  The result is cached
+    /// and used by every later search, even if `calibrate` is disabled in the
+    /// [`SeedFinderConfig`].
+    pub fn calibrate(&self) -> Result<&Calibration> {
+        if let Some(calibration) = self.calibration.get() {
+            return Ok(calibration);
+        }
+
+        let calibration =
+            Calibration::measure(&self.predictors, &self.initial_state, &self.seed_range)?;
+        // Another thread may have won the race but both results are usable.
+        let _ = self.calibration.set(calibration);
+        Ok(self.calibration.get().unwrap())
+    }
+
+    /// Estimate how many seeds will match and how long a search will take.
+    ///
+    /// Runs every predictor against `samples` pseudo-random seeds from the
+    /// search range.  The same config always samples the same seeds.
+    pub fn estimate(&self, samples: usize) -> Result<Estimate> {
+        let order = self.predictor_order()?;
+        Estimate::measure(
+            &self.predictors,
+            &self.initial_state,
+            &self.seed_range,
+            &order,
+            (!self.ranked).then_some(self.max_seeds),
+            samples,
+        )
+    }
+
+    /// Tally the outcome of every predictor across the whole seed range.
+    ///
+    /// Each predictor's [`Predictor::outcome`] is counted for every seed,
+    /// regardless of whether the other predictors match, building a histogram
+    /// per predictor.  Stops early, returning statistics for the seeds scanned
+    /// so far, if `cancel` is cancelled.
+    ///
+    /// `progress` is called periodically with the approximate number of seeds
+    /// scanned so far, out of [`SeedFinder::seed_count`].
+    pub fn statistics<P>(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        cancel: &CancelHandle,
+        progress: P,
+    ) -> Result<Statistics>
+    where
+        P: Fn(u64) + Send + Sync,
+    {
+        Statistics::measure(
+            &self.predictors,
+            game_data,
+            locale,
+            &self.initial_state,
+            &self.seed_range,
+            cancel,
+            &progress,
+        )
+    }
+
+    /// Order in which predictors are evaluated while searching.
+    fn predictor_order(&self) -> Result<Vec<usize>> {
+        if self.calibrate {
+            self.calibrate()?;
+        }
+        Ok(match self.calibration.get() {
+            Some(calibration) => calibration.order.clone(),
+            None => (0..self.predictors.len()).collect(),
+        })
+    }
+
+    /// Run all predictors against `seed` in `order`.
+    ///
+    /// Returns `Some(Ok(seed))` if all predictors match, `None` if any
+    /// predictor doesn't match, or `Some(Err(_))` describing the seed and
+    /// predictor if a predictor fails.
+    fn check_seed(&self, seed: i32, order: &[usize]) -> Option<Result<i32>> {
+        let state = PredictionGameState {
+            game_id: seed as u32,
+            ..self.initial_state
+        };
+
+        for &i in order {
+            match self.predictors[i]
+                .predict(&state)
+                .with_context(|| format!("predictors[{i}] failed on seed {seed}"))
+            {
+                Ok(true) => (),
+                Ok(false) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        Some(Ok(seed))
+    }
+
+    /// Check each predictor against a seed.
+    ///
+    /// Returns whether each of the predictors configured in the
+    /// [`SeedFinderConfig`] matches `seed`, in configuration order.
+    pub fn verify(&self, seed: i32) -> Result<Vec<bool>> {
+        let state = PredictionGameState {
+            game_id: seed as u32,
+            ..self.initial_state
+        };
+
+        self.predictors
+            .iter()
+            .map(|predictor| predictor.predict(&state))
+            .collect()
+    }
+
+    /// Explain why a seed does or doesn't match.
+    ///
+    /// Returns an [`Explanation`] for each of the predictors configured in the
+    /// [`SeedFinderConfig`], in configuration order.  Unlike searching, every
+    /// predictor is run even if an earlier one doesn't match.
+    pub fn explain(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        seed: i32,
+    ) -> Result<Vec<Explanation>> {
+        let state = PredictionGameState {
+            game_id: seed as u32,
+            ..self.initial_state
+        };
+
+        self.predictors
+            .iter()
+            .map(|predictor| predictor.explain(game_data, locale, &state))
+            .collect()
     }
 
     /// Generate report for a seed
@@ -507,4 +1380,303 @@ impl SeedFinder {
         }
         Ok(())
     }
+
+    /// Generate a structured report for a seed
+    ///
+    /// Like [`SeedFinder::report`] but returns a [`SeedReport`] with a
+    /// [`PredictorReport`] for each of the configured predictors.
+    pub fn report_structured(
+        &self,
+        game_data: &GameData,
+        locale: &Locale,
+        seed: i32,
+    ) -> Result<SeedReport> {
+        let state = PredictionGameState {
+            game_id: seed as u32,
+            ..self.initial_state
+        };
+
+        let predictors = self
+            .predictors
+            .iter()
+            .map(|predictor| predictor.report_structured(game_data, locale, &state))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(SeedReport { seed, predictors })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Matches seeds that are a multiple of its value.
+    #[derive(Debug)]
+    struct Multiple(i32);
+
+    impl Predictor for Multiple {
+        fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+            Ok((state.game_id as i32) % self.0 == 0)
+        }
+
+        fn report(
+            &self,
+            _game_data: &GameData,
+            _locale: &Locale,
+            _state: &PredictionGameState,
+            _writer: &mut dyn Write,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        fn report_structured(
+            &self,
+            _game_data: &GameData,
+            _locale: &Locale,
+            _state: &PredictionGameState,
+        ) -> Result<PredictorReport> {
+            unimplemented!()
+        }
+    }
+
+    /// Matches every tenth seed and cancels the search when it reaches
+    /// `seed`.
+    #[derive(Debug)]
+    struct CancelAt {
+        seed: i32,
+        cancel: CancelHandle,
+    }
+
+    impl Predictor for CancelAt {
+        fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+            let seed = state.game_id as i32;
+            if seed >= self.seed {
+                self.cancel.cancel();
+            }
+            Ok(seed % 10 == 0)
+        }
+
+        fn report(
+            &self,
+            _game_data: &GameData,
+            _locale: &Locale,
+            _state: &PredictionGameState,
+            _writer: &mut dyn Write,
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        fn report_structured(
+            &self,
+            _game_data: &GameData,
+            _locale: &Locale,
+            _state: &PredictionGameState,
+        ) -> Result<PredictorReport> {
+            unimplemented!()
+        }
+    }
+
+    fn seed_finder(
+        seed_range: RangeInclusive<i32>,
+        max_seeds: usize,
+        predictor: impl Predictor + 'static,
+    ) -> SeedFinder {
+        SeedFinder {
+            max_seeds,
+            seed_range,
+            deterministic: false,
+            ranked: false,
+            calibrate: false,
+            calibration: OnceLock::new(),
+            initial_state: PredictionGameState::default(),
+            predictors: vec![Box::new(predictor)],
+            config_hash: 0,
+            checkpoint_path: None,
+            resume_checkpoint: None,
+        }
+    }
+
+    #[test]
+    fn honors_seed_range() {
+        let mut finder = seed_finder(-25..=25, 100, Multiple(5));
+        let expected = (-25..=25).step_by(5).collect::<Vec<_>>();
+
+        let mut seeds = finder.find_seeds().unwrap();
+        seeds.sort();
+        assert_eq!(seeds, expected);
+
+        finder.deterministic = true;
+        assert_eq!(finder.find_seeds().unwrap(), expected);
+
+        finder.seed_range = -24..=24;
+        assert_eq!(
+            finder.find_seeds().unwrap(),
+            (-20..=20).step_by(5).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn deterministic_returns_lowest_seeds_in_order() {
+        let mut finder = seed_finder(0..=5_000_000, 2, Multiple(3));
+        finder.deterministic = true;
+        assert_eq!(finder.find_seeds().unwrap(), vec![0, 3]);
+
+        // Matches spread across several chunks.
+        let mut finder = seed_finder(1..=5_000_000, 3, Multiple(1_500_000));
+        finder.deterministic = true;
+        assert_eq!(
+            finder.find_seeds().unwrap(),
+            vec![1_500_000, 3_000_000, 4_500_000]
+        );
+    }
+
+    #[test]
+    fn cancel_returns_partial_results() {
+        let cancel = CancelHandle::default();
+        let cancel_at = 2 * CHUNK_SIZE as i32 + 1000;
+        let mut finder = seed_finder(
+            0..=5_000_000,
+            usize::MAX,
+            CancelAt {
+                seed: cancel_at,
+                cancel: cancel.clone(),
+            },
+        );
+        finder.deterministic = true;
+        let seeds = finder.search(&cancel, |_| (), |_| ()).unwrap();
+
+        // Chunks before the cancelled one are complete and nothing past it
+        // was searched.
+        assert!(cancel.is_cancelled());
+        let complete = (0..2 * CHUNK_SIZE as i32).step_by(10).collect::<Vec<_>>();
+        assert_eq!(seeds[..complete.len()], complete);
+        assert!(seeds.iter().all(|seed| (*seed as i64) < 3 * CHUNK_SIZE));
+    }
+
+    #[test]
+    fn cancel_async_search() {
+        let finder = Arc::new(seed_finder(0..=i32::MAX, 10, Multiple(i32::MAX)));
+        let (rx, cancel) = SeedFinder::find_seeds_async(finder, 1000);
+        cancel.cancel();
+        loop {
+            match rx.recv().unwrap() {
+                Progress::Cancelled(seeds) => {
+                    assert!(seeds.len() <= 2);
+                    break;
+                }
+                Progress::Complete(_) => panic!("search wasn't cancelled"),
+                Progress::Error(e) => panic!("search failed: {e}"),
+                _ => (),
+            }
+        }
+    }
+
+    fn checkpoint_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("seed-maker-{}-{name}.json", std::process::id()))
+    }
+
+    /// Run a checkpointed search with `max_seeds`, resuming from the last
+    /// run's checkpoint if there is one.
+    fn resumed_search(path: &Path, max_seeds: usize) -> Vec<i32> {
+        let mut finder = seed_finder(0..=3 * CHUNK_SIZE as i32, max_seeds, Multiple(10));
+        if path.exists() {
+            finder.resume_from(path).unwrap();
+        }
+        finder.checkpoint_to(path);
+        finder.find_seeds().unwrap()
+    }
+
+    #[test]
+    fn resume_with_different_max_seeds() {
+        let path = checkpoint_path("resume-max-seeds");
+        let _ = std::fs::remove_file(&path);
+        let expected = |max_seeds| {
+            let mut finder = seed_finder(0..=3 * CHUNK_SIZE as i32, max_seeds, Multiple(10));
+            finder.deterministic = true;
+            finder.find_seeds().unwrap()
+        };
+
+        // Each run stops in the first chunk with matches left unreturned.
+        for max_seeds in [5, 10, 2, 20] {
+            assert_eq!(
+                resumed_search(&path, max_seeds),
+                expected(max_seeds),
+                "max_seeds {max_seeds}"
+            );
+        }
+
+        // A run that needs more than the checkpoint holds keeps searching.
+        let max_seeds = CHUNK_SIZE as usize / 10 + 100;
+        assert_eq!(resumed_search(&path, max_seeds), expected(max_seeds));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn resume_cancelled_search() {
+        let path = checkpoint_path("resume-cancelled");
+        let _ = std::fs::remove_file(&path);
+        let range = 0..=3 * CHUNK_SIZE as i32;
+
+        let cancel = CancelHandle::default();
+        let mut finder = seed_finder(
+            range.clone(),
+            usize::MAX,
+            CancelAt {
+                seed: 2 * CHUNK_SIZE as i32 + 1000,
+                cancel: cancel.clone(),
+            },
+        );
+        finder.checkpoint_to(&path);
+        finder.search(&cancel, |_| (), |_| ()).unwrap();
+        assert!(cancel.is_cancelled());
+
+        let mut finder = seed_finder(range.clone(), usize::MAX, Multiple(10));
+        finder.resume_from(&path).unwrap();
+        assert_eq!(
+            finder.find_seeds().unwrap(),
+            range.step_by(10).collect::<Vec<_>>()
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn resume_rejects_other_config() {
+        let path = checkpoint_path("resume-other-config");
+        Checkpoint {
+            config_hash: 1,
+            next_seed: 0,
+            seeds: Vec::new(),
+        }
+        .save(&path)
+        .unwrap();
+        let mut finder = seed_finder(0..=100, 10, Multiple(10));
+        assert!(finder.resume_from(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn ranked_search_reports_ranked_seeds() {
+        let mut finder = seed_finder(0..=9, 3, Multiple(5));
+        finder.ranked = true;
+        let events = std::sync::Mutex::new(Vec::new());
+        let seeds = finder
+            .search(
+                &CancelHandle::default(),
+                |_| (),
+                |event| events.lock().unwrap().push(event),
+            )
+            .unwrap();
+
+        // Seed 1 is the best of the non-matching seeds.
+        assert_eq!(seeds, vec![0, 5, 1]);
+        let events = events
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|event| match event {
+                Progress::Ranked(seed, score) => (seed, score),
+                event => panic!("unexpected event {event:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(events, vec![(0, 1.0), (5, 1.0), (1, 0.0)]);
+    }
 }
@@ -0,0 +1,405 @@
+use std::{fmt, marker::PhantomData};
+
+use schemars::JsonSchema;
+use sdv::{
+    predictor::PredictionGameState,
+    rng::{Rng, SeedGenerator},
+    GameData, Locale,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{Explanation, Predictor, PredictorReport, Result};
+
+/// Deepest floor of the mines.
+pub(crate) const MAX_MINE_LEVEL: u32 = 120;
+
+/// Chance of an eligible floor being infested with monsters or slimes.
+const INFESTED_CHANCE: f64 = 0.044;
+
+/// Chance of an infested floor being a monster floor instead of a slime floor.
+const MONSTER_CHANCE: f64 = 0.5;
+
+/// Chance of an eligible floor being a mushroom floor.
+const MUSHROOM_CHANCE: f64 = 0.035;
+
+/// Type of a mine floor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, JsonSchema, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FloorType {
+    /// A regular floor.
+    Normal,
+
+    /// A floor lit with rainbow lights where mushrooms replace stones.
+    ///
+    /// Only found between floors 81 and 119.
+    Mushroom,
+
+    /// A floor infested with monsters.
+    Monster,
+
+    /// A floor infested with slimes.
+    Slime,
+}
+
+impl fmt::Display for FloorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FloorType::Normal => "Normal",
+            FloorType::Mushroom => "Mushroom",
+            FloorType::Monster => "Monster",
+            FloorType::Slime => "Slime",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Returns true if `level` can be a monster or slime floor.
+///
+/// Elevator floors and the floors near the top and bottom of each 40 floor
+/// area are never infested.
+fn can_be_infested(level: u32) -> bool {
+    let area_level = level % 40;
+    !level.is_multiple_of(5) && area_level > 5 && area_level < 30 && area_level != 19
+}
+
+/// Returns true if `level` can be a mushroom floor.
+fn can_be_mushroom(level: u32) -> bool {
+    level > 80 && level < MAX_MINE_LEVEL && !level.is_multiple_of(5)
+}
+
+/// Floor types `level` can have on any day.
+pub(crate) fn possible_floor_types(level: u32) -> Vec<FloorType> {
+    let mut floor_types = vec![FloorType::Normal];
+    if can_be_mushroom(level) {
+        floor_types.push(FloorType::Mushroom);
+    }
+    if can_be_infested(level) {
+        floor_types.push(FloorType::Monster);
+        floor_types.push(FloorType::Slime);
+    }
+    floor_types
+}
+
+/// Predict the type of mine floor `level` on the day of `state`.
+///
+/// Mirrors the floor type rolls in `StardewValley.Locations.MineShaft`'s
+/// `loadLevel`.  The game seeds the RNG with
+/// `Utility.CreateDaySaveRandom(level * 100)` (`DaysPlayed + level * 100 +
+/// uniqueIDForThisGame / 2` before 1.6) so every visit on the same day gets the
+/// same floor type.  The first roll is the [`INFESTED_CHANCE`] check, made
+/// before the floor's eligibility is checked, followed by the
+/// [`MONSTER_CHANCE`] roll on infested floors.  Floors that aren't infested
+/// roll [`MUSHROOM_CHANCE`] if they are between floors 81 and 119.
+///
+/// Buffs and quests that change floor types aren't simulated.
+pub(crate) fn predict_floor_type<G: SeedGenerator>(
+    state: &PredictionGameState,
+    level: u32,
+) -> FloorType {
+    let seed = G::generate_day_save_seed(
+        state.days_played,
+        state.game_id,
+        (level * 100) as f64,
+        0.0,
+        0.0,
+    );
+    let mut rng = Rng::new(seed);
+
+    if rng.next_double() < INFESTED_CHANCE && can_be_infested(level) {
+        return if rng.next_double() < MONSTER_CHANCE {
+            FloorType::Monster
+        } else {
+            FloorType::Slime
+        };
+    }
+    if can_be_mushroom(level) && rng.next_double() < MUSHROOM_CHANCE {
+        return FloorType::Mushroom;
+    }
+    FloorType::Normal
+}
+
+/// Configuration for [`MineLevel`].
+///
+/// ## Example JSON
+/// ```text
+/// {
+///     "type": "mine_level",
+///     "start_level": 1,
+///     "end_level": 40,
+///     "floors": [
+///         { "level": 13, "floor_type": "monster" }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct MineLevelConfig {
+    /// First floor included in reports (inclusive).
+    ///
+    /// Defaults to 1.
+    #[serde(default = "crate::one")]
+    pub start_level: u32,
+
+    /// Last floor included in reports (inclusive).
+    ///
+    /// Defaults to 120.
+    #[serde(default = "max_mine_level")]
+    pub end_level: u32,
+
+    /// Floor types required at specific floors.
+    #[serde(default)]
+    pub floors: Vec<MineFloorConfig>,
+}
+
+fn max_mine_level() -> u32 {
+    MAX_MINE_LEVEL
+}
+
+/// A floor type required by a [`MineLevelConfig`].
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct MineFloorConfig {
+    /// Floor of the mines, between 1 and 120.
+    pub level: u32,
+
+    /// Required type of the floor.
+    #[serde(serialize_with = "crate::config_format::serialize_variant_name")]
+    pub floor_type: FloorType,
+}
+
+/// Structured report of a [`MineLevel`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MineLevelReport {
+    /// Type of every floor between `start_level` and `end_level`.
+    pub floors: Vec<MineFloorReport>,
+}
+
+/// Structured report of a single mine floor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MineFloorReport {
+    /// Floor of the mines.
+    pub level: u32,
+
+    /// Type of the floor.
+    pub floor_type: FloorType,
+}
+
+/// Predictor for the types of mine floors on a day.
+///
+/// Configured through [`MineLevelConfig`].
+///
+/// Matches if every configured floor has its required type on the day of the
+/// game state.  Reports list the type of every floor between `start_level`
+/// and `end_level`.
+pub struct MineLevel<G: Send + Sync + SeedGenerator> {
+    start_level: u32,
+    end_level: u32,
+    floors: Vec<MineFloorConfig>,
+    phantom: PhantomData<G>,
+}
+
+impl<G: Send + Sync + SeedGenerator> MineLevel<G> {
+    /// Create a new [`MineLevel`] from a [`MineLevelConfig`].
+    pub fn new(config: &MineLevelConfig) -> Result<Self> {
+        Ok(Self {
+            start_level: config.start_level,
+            end_level: config.end_level,
+            floors: config.floors.clone(),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Number of configured floors with their required type.
+    fn matched_floors(&self, state: &PredictionGameState) -> usize {
+        self.floors
+            .iter()
+            .filter(|floor| predict_floor_type::<G>(state, floor.level) == floor.floor_type)
+            .count()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> fmt::Debug for MineLevel<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MineLevel")
+            .field("start_level", &self.start_level)
+            .field("end_level", &self.end_level)
+            .field("floors", &self.floors)
+            .finish()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Predictor for MineLevel<G> {
+    fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+        Ok(self
+            .floors
+            .iter()
+            .all(|floor| predict_floor_type::<G>(state, floor.level) == floor.floor_type))
+    }
+
+    /// Scores the fraction of configured floors with their required type.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        if self.floors.is_empty() {
+            return Ok(1.0);
+        }
+
+        Ok(self.matched_floors(state) as f64 / self.floors.len() as f64)
+    }
+
+    /// Buckets seeds by the number of configured floors matched.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let matched = self.matched_floors(state);
+        Ok((
+            matched == self.floors.len(),
+            format!("{matched} of {} floors matched", self.floors.len()),
+        ))
+    }
+
+    fn report(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn std::io::prelude::Write,
+    ) -> Result<()> {
+        writeln!(
+            writer,
+            "Mine floors {}-{}:",
+            self.start_level, self.end_level
+        )?;
+        for floor_type in [FloorType::Mushroom, FloorType::Monster, FloorType::Slime] {
+            let levels = (self.start_level..=self.end_level)
+                .filter(|level| predict_floor_type::<G>(state, *level) == floor_type)
+                .map(|level| level.to_string())
+                .collect::<Vec<_>>();
+            let levels = if levels.is_empty() {
+                "-".to_string()
+            } else {
+                levels.join(", ")
+            };
+            writeln!(writer, "  {:<9} {levels}", format!("{floor_type}:"))?;
+        }
+        Ok(())
+    }
+
+    fn report_structured(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let floors = (self.start_level..=self.end_level)
+            .map(|level| MineFloorReport {
+                level,
+                floor_type: predict_floor_type::<G>(state, level),
+            })
+            .collect();
+        Ok(PredictorReport::MineLevel(MineLevelReport { floors }))
+    }
+
+    fn explain(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let mut mismatches = Vec::new();
+        for floor in &self.floors {
+            let floor_type = predict_floor_type::<G>(state, floor.level);
+            if floor_type != floor.floor_type {
+                mismatches.push(format!(
+                    "floor {}: got {floor_type}, need {}",
+                    floor.level, floor.floor_type
+                ));
+            }
+        }
+        let detail = if mismatches.is_empty() {
+            format!("Mine: all {} floors matched", self.floors.len())
+        } else {
+            format!("Mine: {}", mismatches.join(", "))
+        };
+        Ok(Explanation::new(mismatches.is_empty(), Some(detail)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sdv::rng::{HashedSeedGenerator, LegacySeedGenerator};
+
+    use super::*;
+
+    /// Known floor types as `(game_id, days_played, level, floor_type)`.
+    ///
+    /// Computed with a port of .NET's `System.Random` and the game's
+    /// `Utility.CreateDaySaveRandom` seeding that is independent of `sdv`.
+    const HASHED_FLOORS: [(u32, u32, u32, FloorType); 10] = [
+        (123456789, 5, 13, FloorType::Normal),
+        (123456978, 5, 13, FloorType::Monster),
+        (123456999, 5, 13, FloorType::Slime),
+        (123456838, 31, 27, FloorType::Monster),
+        (123457104, 31, 27, FloorType::Slime),
+        (123456824, 113, 86, FloorType::Mushroom),
+        (123456810, 200, 97, FloorType::Slime),
+        (123456915, 200, 97, FloorType::Mushroom),
+        // Floor 99 can't be infested but still draws the infestation roll
+        // before the mushroom roll.
+        (123456836, 60, 99, FloorType::Normal),
+        (123456789, 60, 99, FloorType::Mushroom),
+    ];
+
+    /// Like [`HASHED_FLOORS`] for the legacy RNG.
+    const LEGACY_FLOORS: [(u32, u32, u32, FloorType); 10] = [
+        (123456789, 5, 13, FloorType::Normal),
+        (123456915, 5, 13, FloorType::Monster),
+        (123457048, 5, 13, FloorType::Slime),
+        (123457006, 31, 27, FloorType::Monster),
+        (123456873, 31, 27, FloorType::Slime),
+        (123456908, 113, 86, FloorType::Mushroom),
+        (123456803, 200, 97, FloorType::Slime),
+        (123456838, 200, 97, FloorType::Mushroom),
+        (123456816, 60, 99, FloorType::Normal),
+        (123456902, 60, 99, FloorType::Mushroom),
+    ];
+
+    fn state(game_id: u32, days_played: u32) -> PredictionGameState {
+        PredictionGameState {
+            game_id,
+            days_played,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn known_floor_types() {
+        for (game_id, days_played, level, floor_type) in HASHED_FLOORS {
+            assert_eq!(
+                predict_floor_type::<HashedSeedGenerator>(&state(game_id, days_played), level),
+                floor_type,
+                "hashed game {game_id} day {days_played} floor {level}"
+            );
+        }
+        for (game_id, days_played, level, floor_type) in LEGACY_FLOORS {
+            assert_eq!(
+                predict_floor_type::<LegacySeedGenerator>(&state(game_id, days_played), level),
+                floor_type,
+                "legacy game {game_id} day {days_played} floor {level}"
+            );
+        }
+    }
+
+    #[test]
+    fn floor_types_are_possible() {
+        for game_id in 0..500 {
+            let state = state(game_id, 1);
+            for level in 1..=MAX_MINE_LEVEL {
+                let floor_type = predict_floor_type::<HashedSeedGenerator>(&state, level);
+                assert!(
+                    possible_floor_types(level).contains(&floor_type),
+                    "game {game_id} floor {level} is {floor_type}"
+                );
+            }
+        }
+    }
+}
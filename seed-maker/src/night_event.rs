@@ -1,5 +1,6 @@
 use std::{fmt::Debug, marker::PhantomData};
 
+use schemars::JsonSchema;
 use sdv::{
     predictor::{self, night_event::predict_night_event, PredictionGameState},
     rng::SeedGenerator,
@@ -7,7 +8,7 @@ use sdv::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{Predictor, Result};
+use crate::{Explanation, Predictor, PredictorReport, Result};
 
 /// Configuration for [`NightEvent`].
 ///
@@ -18,9 +19,18 @@ use crate::{Predictor, Result};
 ///     "event": "fairy"
 /// }
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
 pub struct NightEventConfig {
     /// Night event to search for.
+    #[schemars(schema_with = "crate::schema::night_event_schema")]
+    #[serde(serialize_with = "crate::config_format::serialize_variant_name")]
+    pub event: predictor::night_event::NightEvent,
+}
+
+/// Structured report of a [`NightEvent`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NightEventReport {
+    /// Night event that occurs.
     pub event: predictor::night_event::NightEvent,
 }
 
@@ -67,4 +77,39 @@ impl<G: Send + Sync + SeedGenerator> Predictor for NightEvent<G> {
         writeln!(writer, "Night Event: {night_event:?}")?;
         Ok(())
     }
+
+    fn report_structured(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let mut state = state.clone();
+        let event = predict_night_event::<G>(&mut state);
+        Ok(PredictorReport::NightEvent(NightEventReport { event }))
+    }
+
+    /// Buckets seeds by the night event.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let mut state = state.clone();
+        let night_event = predict_night_event::<G>(&mut state);
+        Ok((night_event == self.event, format!("{night_event:?}")))
+    }
+
+    fn explain(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let mut state = state.clone();
+        let night_event = predict_night_event::<G>(&mut state);
+        let detail = format!("Night Event: got {night_event:?}, need {:?}", self.event);
+        Ok(Explanation::new(night_event == self.event, Some(detail)))
+    }
 }
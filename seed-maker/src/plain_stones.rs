@@ -0,0 +1,830 @@
+use std::{fmt, marker::PhantomData};
+
+use anyhow::{anyhow, bail};
+use schemars::JsonSchema;
+use sdv::{
+    predictor::PredictionGameState,
+    rng::{Rng, SeedGenerator},
+    GameData, Locale,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    mine_level::{predict_floor_type, MAX_MINE_LEVEL},
+    Explanation, FloorType, Predictor, PredictorReport, Result,
+};
+
+/// Base chance of a stone spawning the ladder down.
+const LADDER_CHANCE: f64 = 0.02;
+
+/// Ladder chance added once every monster on the floor is killed.
+const CLEARED_LADDER_CHANCE: f64 = 0.04;
+
+/// Base chance of a stone dropping a geode.
+const GEODE_CHANCE: f64 = 0.022;
+
+/// Base chance of a stone dropping an omni geode deeper than floor 20.
+const OMNI_GEODE_CHANCE: f64 = 0.005;
+
+/// Base chance of a stone dropping ore.
+const ORE_CHANCE: f64 = 0.05;
+
+/// Ore chance multiplier of plain stones.
+const ORE_MODIFIER: f64 = 0.8;
+
+/// Chance of a stone that drops ore also dropping coal.
+const COAL_CHANCE: f64 = 0.25;
+
+/// Largest tile coordinate accepted for a stone.
+///
+/// Mine floors are far smaller; the bound keeps `x * 1000` in the seed from
+/// overflowing.
+pub(crate) const MAX_TILE: i32 = 10_000;
+
+/// Item dropped by breaking a stone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, JsonSchema, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StoneDrop {
+    /// The geode of the floor's area: a Geode, Frozen Geode or Magma Geode.
+    Geode,
+
+    /// An Omni Geode.
+    OmniGeode,
+
+    /// The ore of the floor: Copper, Iron or Gold Ore.
+    Ore,
+
+    /// Coal, only dropped alongside ore.
+    Coal,
+}
+
+impl fmt::Display for StoneDrop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            StoneDrop::Geode => "Geode",
+            StoneDrop::OmniGeode => "Omni Geode",
+            StoneDrop::Ore => "Ore",
+            StoneDrop::Coal => "Coal",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Tile position of a stone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, JsonSchema, Serialize)]
+pub struct MineTile {
+    /// Column of the tile.
+    pub x: i32,
+
+    /// Row of the tile.
+    pub y: i32,
+}
+
+/// Drops required by a [`PlainStonesConfig`].
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct StoneDropConfig {
+    /// Item required.
+    #[serde(serialize_with = "crate::config_format::serialize_variant_name")]
+    pub drop: StoneDrop,
+
+    /// Minimum number of stones that must drop it.
+    ///
+    /// Defaults to 1.
+    #[serde(default = "crate::one")]
+    pub count: u32,
+}
+
+/// Configuration for [`PlainStones`].
+///
+/// ## Example JSON
+/// ```text
+/// {
+///     "type": "plain_stones",
+///     "level": 5,
+///     "stones_on_level": 42,
+///     "stones": [
+///         { "x": 12, "y": 8 },
+///         { "x": 13, "y": 8 },
+///         { "x": 13, "y": 9 }
+///     ],
+///     "prospector": true,
+///     "ladder_within": 3,
+///     "drops": [
+///         { "drop": "coal" }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
+pub struct PlainStonesConfig {
+    /// Floor of the mines the stones are on.
+    ///
+    /// Defaults to the floor below `deepest_mine_level`, the first floor the
+    /// player hasn't reached yet.
+    #[serde(default)]
+    pub level: Option<u32>,
+
+    /// Number of stones on the floor when the player arrives.
+    pub stones_on_level: u32,
+
+    /// Tiles of the stones broken, in the order they are broken.
+    pub stones: Vec<MineTile>,
+
+    /// Player's mining level.
+    ///
+    /// Defaults to 0.
+    #[serde(default)]
+    pub mining_level: u32,
+
+    /// Player's luck level from buffs.
+    ///
+    /// Defaults to 0.
+    #[serde(default)]
+    pub luck_level: u32,
+
+    /// True if the player has the Excavator profession, doubling the chance
+    /// of geodes.
+    ///
+    /// Defaults to false.
+    #[serde(default)]
+    pub excavator: bool,
+
+    /// True if the player has the Prospector profession, doubling the chance
+    /// of coal.
+    ///
+    /// Defaults to false.
+    #[serde(default)]
+    pub prospector: bool,
+
+    /// True if every monster on the floor is killed before the stones are
+    /// broken, raising the ladder chance.
+    ///
+    /// Defaults to false.
+    #[serde(default)]
+    pub monsters_cleared: bool,
+
+    /// Require the ladder down to spawn within this many stones.
+    ///
+    /// Defaults to no ladder requirement.
+    #[serde(default)]
+    pub ladder_within: Option<u32>,
+
+    /// Drops required across all stones broken.
+    #[serde(default)]
+    pub drops: Vec<StoneDropConfig>,
+}
+
+/// Structured report of a [`PlainStones`] predictor.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PlainStonesReport {
+    /// Floor of the mines the stones are on.
+    pub level: u32,
+
+    /// Position, starting at 1, of the stone that spawned the ladder down.
+    pub ladder_stone: Option<u32>,
+
+    /// Results of breaking each stone.
+    pub stones: Vec<PlainStoneReport>,
+}
+
+/// Structured report of breaking a single stone.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PlainStoneReport {
+    /// Tile of the stone.
+    pub tile: MineTile,
+
+    /// Chance of the stone spawning the ladder down.
+    ///
+    /// `None` if the stone doesn't roll for a ladder: the ladder has already
+    /// spawned, the floor is infested, the stone is the last on the floor or
+    /// the floor is the bottom of the mines.
+    pub ladder_chance: Option<f64>,
+
+    /// True if the stone spawned the ladder down.
+    pub ladder: bool,
+
+    /// Items dropped by the stone.
+    pub drops: Vec<StoneDrop>,
+}
+
+/// Result of breaking a single stone.
+struct StoneBreak {
+    ladder_chance: Option<f64>,
+    ladder: bool,
+    drops: Vec<StoneDrop>,
+}
+
+/// Predictor for ladders and drops from breaking plain stones on mine floors 1
+/// to 120.
+///
+/// Configured through [`PlainStonesConfig`].
+///
+/// Simulates the rolls the game makes when a plain stone is broken.  Each
+/// stone's RNG is seeded from the day, the floor and the stone's tile so the
+/// stones broken, and the order they are broken in, must be configured.  The
+/// chance of a ladder grows as fewer stones are left on the floor and the
+/// last stone always spawns one.  Monster and slime floors never spawn
+/// ladders from stones.
+///
+/// Mirrors `StardewValley.Locations.MineShaft`'s `checkStoneForItems`:
+///
+/// 1. The RNG is `Utility.CreateDaySaveRandom(x * 1000, y, mineLevel)` and
+///    its first value is discarded.
+/// 2. Unless the ladder has spawned or the floor is infested, the ladder
+///    rolls against [`LADDER_CHANCE`] plus `1 / stonesLeftOnThisLevel`, the
+///    luck level / 100, daily luck / 5 and [`CLEARED_LADDER_CHANCE`] once the
+///    monsters are cleared.  The last stone spawns the ladder without a roll
+///    and floor 120 rolls but never spawns one.
+/// 3. [`GEODE_CHANCE`], then [`OMNI_GEODE_CHANCE`] deeper than floor 20, doubled
+///    by Excavator.
+/// 4. [`ORE_CHANCE`] times [`ORE_MODIFIER`], and if ore drops,
+///    [`COAL_CHANCE`] doubled by Prospector for coal alongside it.
+///
+/// Not simulated:
+///
+/// - Shafts.  They only appear in the Skull Cavern, deeper than floor 120,
+///   and whether a ladder becomes a shaft is drawn from the floor's own RNG,
+///   which depends on how the whole floor was generated.
+/// - Ore and gem nodes.  `GameLocation.breakStone` handles them with per-node
+///   drop tables and returns before the rolls above, so they need a node
+///   type per stone and share nothing with plain stones.
+/// - The 1.2 ore modifier of the two darker plain stone variants.
+/// - Dwarf statue buffs on the ladder, ore and coal chances.
+/// - The ore type roll in `getOreIdForLevel`.  It is the last roll made so
+///   only which ore drops is unknown.
+pub struct PlainStones<G: Send + Sync + SeedGenerator> {
+    level: Option<u32>,
+    stones_on_level: u32,
+    stones: Vec<MineTile>,
+    mining_level: u32,
+    luck_level: u32,
+    excavator: bool,
+    prospector: bool,
+    monsters_cleared: bool,
+    ladder_within: Option<u32>,
+    drops: Vec<StoneDropConfig>,
+    phantom: PhantomData<G>,
+}
+
+impl<G: Send + Sync + SeedGenerator> PlainStones<G> {
+    /// Create a new [`PlainStones`] from a [`PlainStonesConfig`].
+    pub fn new(config: &PlainStonesConfig) -> Result<Self> {
+        Ok(Self {
+            level: config.level,
+            stones_on_level: config.stones_on_level,
+            stones: config.stones.clone(),
+            mining_level: config.mining_level,
+            luck_level: config.luck_level,
+            excavator: config.excavator,
+            prospector: config.prospector,
+            monsters_cleared: config.monsters_cleared,
+            ladder_within: config.ladder_within,
+            drops: config.drops.clone(),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Floor of the mines the stones are on.
+    fn level(&self, state: &PredictionGameState) -> Result<u32> {
+        let level = match self.level {
+            Some(level) => level,
+            None => state
+                .deepest_mine_level
+                .checked_add(1)
+                .ok_or_else(|| anyhow!("deepest_mine_level is too deep"))?,
+        };
+        if !(1..=MAX_MINE_LEVEL).contains(&level) {
+            bail!("{level} is not a mine floor between 1 and {MAX_MINE_LEVEL}");
+        }
+        Ok(level)
+    }
+
+    /// Break every configured stone in order.
+    fn break_stones(&self, state: &PredictionGameState) -> Result<Vec<StoneBreak>> {
+        let level = self.level(state)?;
+        let infested = matches!(
+            predict_floor_type::<G>(state, level),
+            FloorType::Monster | FloorType::Slime
+        );
+        let chance_modifier = state.daily_luck / 2.0
+            + self.mining_level as f64 * 0.005
+            + self.luck_level as f64 * 0.001;
+        let geode_multiplier = if self.excavator { 2.0 } else { 1.0 };
+        let coal_multiplier = if self.prospector { 2.0 } else { 1.0 };
+
+        let mut stones_left = self.stones_on_level;
+        let mut ladder_spawned = false;
+        let mut breaks = Vec::with_capacity(self.stones.len());
+        for tile in &self.stones {
+            let x = tile
+                .x
+                .checked_mul(1000)
+                .ok_or_else(|| anyhow!("stone x {} is out of range", tile.x))?;
+            let seed = G::generate_day_save_seed(
+                state.days_played,
+                state.game_id,
+                x as f64,
+                tile.y as f64,
+                level as f64,
+            );
+            let mut rng = Rng::new(seed);
+            rng.next_double();
+
+            stones_left = stones_left.saturating_sub(1);
+            let mut ladder_chance = LADDER_CHANCE
+                + 1.0 / stones_left.max(1) as f64
+                + self.luck_level as f64 / 100.0
+                + state.daily_luck / 5.0;
+            if self.monsters_cleared {
+                ladder_chance += CLEARED_LADDER_CHANCE;
+            }
+            // The roll is skipped once the ladder has spawned, on infested
+            // floors and for the last stone.  Floor 120 still rolls.
+            let rolls_ladder = !ladder_spawned && !infested && stones_left > 0;
+            let ladder = !ladder_spawned
+                && !infested
+                && (stones_left == 0 || rng.next_double() < ladder_chance)
+                && level < MAX_MINE_LEVEL;
+            ladder_spawned |= ladder;
+
+            let mut drops = Vec::new();
+            if rng.next_double() < GEODE_CHANCE * (1.0 + chance_modifier) * geode_multiplier {
+                drops.push(StoneDrop::Geode);
+            }
+            if level > 20
+                && rng.next_double()
+                    < OMNI_GEODE_CHANCE * (1.0 + chance_modifier) * geode_multiplier
+            {
+                drops.push(StoneDrop::OmniGeode);
+            }
+            if rng.next_double() < ORE_CHANCE * (1.0 + chance_modifier) * ORE_MODIFIER {
+                if rng.next_double() < COAL_CHANCE * coal_multiplier {
+                    drops.push(StoneDrop::Coal);
+                }
+                drops.push(StoneDrop::Ore);
+            }
+
+            breaks.push(StoneBreak {
+                ladder_chance: (rolls_ladder && level < MAX_MINE_LEVEL).then_some(ladder_chance),
+                ladder,
+                drops,
+            });
+        }
+        Ok(breaks)
+    }
+
+    /// Number of requirements and number of those met.
+    fn met_requirements(&self, breaks: &[StoneBreak]) -> (usize, usize) {
+        let mut met = 0;
+        let mut required = self.drops.len();
+        if let Some(within) = self.ladder_within {
+            required += 1;
+            if ladder_stone(breaks).is_some_and(|stone| stone <= within) {
+                met += 1;
+            }
+        }
+        met += self
+            .drops
+            .iter()
+            .filter(|drop| drop_count(breaks, drop.drop) >= drop.count)
+            .count();
+        (met, required)
+    }
+}
+
+/// Position, starting at 1, of the stone that spawned the ladder down.
+fn ladder_stone(breaks: &[StoneBreak]) -> Option<u32> {
+    breaks
+        .iter()
+        .position(|stone| stone.ladder)
+        .map(|i| i as u32 + 1)
+}
+
+/// Number of stones that dropped `drop`.
+fn drop_count(breaks: &[StoneBreak], drop: StoneDrop) -> u32 {
+    breaks
+        .iter()
+        .filter(|stone| stone.drops.contains(&drop))
+        .count() as u32
+}
+
+impl<G: Send + Sync + SeedGenerator> fmt::Debug for PlainStones<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PlainStones")
+            .field("level", &self.level)
+            .field("stones_on_level", &self.stones_on_level)
+            .field("stones", &self.stones)
+            .field("mining_level", &self.mining_level)
+            .field("luck_level", &self.luck_level)
+            .field("excavator", &self.excavator)
+            .field("prospector", &self.prospector)
+            .field("monsters_cleared", &self.monsters_cleared)
+            .field("ladder_within", &self.ladder_within)
+            .field("drops", &self.drops)
+            .finish()
+    }
+}
+
+impl<G: Send + Sync + SeedGenerator> Predictor for PlainStones<G> {
+    fn predict(&self, state: &PredictionGameState) -> Result<bool> {
+        let (met, required) = self.met_requirements(&self.break_stones(state)?);
+        Ok(met == required)
+    }
+
+    /// Scores the fraction of requirements met.
+    fn score(&self, state: &PredictionGameState) -> Result<f64> {
+        let (met, required) = self.met_requirements(&self.break_stones(state)?);
+        if required == 0 {
+            return Ok(1.0);
+        }
+
+        Ok(met as f64 / required as f64)
+    }
+
+    /// Buckets seeds by the stone that spawns the ladder down.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let breaks = self.break_stones(state)?;
+        let (met, required) = self.met_requirements(&breaks);
+        let outcome = match ladder_stone(&breaks) {
+            Some(stone) => format!("ladder on stone {stone}"),
+            None => "no ladder".to_string(),
+        };
+        Ok((met == required, outcome))
+    }
+
+    fn report(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+        writer: &mut dyn std::io::prelude::Write,
+    ) -> Result<()> {
+        writeln!(writer, "Mine floor {} stones:", self.level(state)?)?;
+        for (i, (tile, stone)) in self
+            .stones
+            .iter()
+            .zip(self.break_stones(state)?)
+            .enumerate()
+        {
+            let mut results = stone
+                .drops
+                .iter()
+                .map(|drop| drop.to_string())
+                .collect::<Vec<_>>();
+            if stone.ladder {
+                results.insert(0, "Ladder".to_string());
+            }
+            let results = if results.is_empty() {
+                "-".to_string()
+            } else {
+                results.join(", ")
+            };
+            writeln!(writer, "  {} ({}, {}): {results}", i + 1, tile.x, tile.y)?;
+        }
+        Ok(())
+    }
+
+    fn report_structured(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let breaks = self.break_stones(state)?;
+        let ladder_stone = ladder_stone(&breaks);
+        let stones = self
+            .stones
+            .iter()
+            .zip(breaks)
+            .map(|(tile, stone)| PlainStoneReport {
+                tile: *tile,
+                ladder_chance: stone.ladder_chance,
+                ladder: stone.ladder,
+                drops: stone.drops,
+            })
+            .collect();
+        Ok(PredictorReport::PlainStones(PlainStonesReport {
+            level: self.level(state)?,
+            ladder_stone,
+            stones,
+        }))
+    }
+
+    fn explain(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &PredictionGameState,
+    ) -> Result<Explanation> {
+        let breaks = self.break_stones(state)?;
+        let ladder = match ladder_stone(&breaks) {
+            Some(stone) => format!("ladder on stone {stone}"),
+            None => format!("no ladder in {} stones", breaks.len()),
+        };
+        let mut details = vec![ladder];
+        if let Some(within) = self.ladder_within {
+            details.push(format!("need ladder within {within} stones"));
+        }
+        for drop in &self.drops {
+            details.push(format!(
+                "{} {}, need {}",
+                drop_count(&breaks, drop.drop),
+                drop.drop,
+                drop.count
+            ));
+        }
+        let (met, required) = self.met_requirements(&breaks);
+        Ok(Explanation::new(
+            met == required,
+            Some(format!(
+                "Mine floor {}: {}",
+                self.level(state)?,
+                details.join(", ")
+            )),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sdv::rng::{HashedSeedGenerator, LegacySeedGenerator};
+
+    use super::*;
+
+    fn config(level: u32, stones_on_level: u32, stones: Vec<MineTile>) -> PlainStonesConfig {
+        PlainStonesConfig {
+            level: Some(level),
+            stones_on_level,
+            stones,
+            mining_level: 3,
+            luck_level: 1,
+            excavator: false,
+            prospector: false,
+            monsters_cleared: false,
+            ladder_within: None,
+            drops: Vec::new(),
+        }
+    }
+
+    fn tiles(count: i32) -> Vec<MineTile> {
+        (0..count)
+            .map(|i| MineTile {
+                x: 10 + i % 7,
+                y: 5 + i / 7,
+            })
+            .collect()
+    }
+
+    fn state(game_id: u32) -> PredictionGameState {
+        PredictionGameState {
+            game_id,
+            days_played: 12,
+            daily_luck: 0.05,
+            ..Default::default()
+        }
+    }
+
+    /// Game id whose `level` is not infested on the day of [`state`].
+    fn uninfested_game(level: u32) -> u32 {
+        (0..)
+            .find(|game_id| {
+                predict_floor_type::<HashedSeedGenerator>(&state(*game_id), level)
+                    == FloorType::Normal
+            })
+            .unwrap()
+    }
+
+    /// Results of each stone formatted like [`PlainStones::report`].
+    fn results<G: SeedGenerator + Send + Sync>(
+        config: &PlainStonesConfig,
+        state: &PredictionGameState,
+    ) -> Vec<String> {
+        PlainStones::<G>::new(config)
+            .unwrap()
+            .break_stones(state)
+            .unwrap()
+            .into_iter()
+            .map(|stone| {
+                let mut results = stone
+                    .drops
+                    .iter()
+                    .map(|drop| drop.to_string())
+                    .collect::<Vec<_>>();
+                if stone.ladder {
+                    results.insert(0, "Ladder".to_string());
+                }
+                if results.is_empty() {
+                    "-".to_string()
+                } else {
+                    results.join(", ")
+                }
+            })
+            .collect()
+    }
+
+    // Known results of breaking 12 stones on floor 45 were computed with a
+    // port of .NET's `System.Random` and the game's
+    // `Utility.CreateDaySaveRandom` seeding that is independent of `sdv`.
+
+    #[test]
+    fn known_hashed_results() {
+        let mut config = config(45, 12, tiles(12));
+        assert_eq!(
+            results::<HashedSeedGenerator>(&config, &state(123456798)),
+            [
+                "Geode",
+                "-",
+                "-",
+                "-",
+                "Ladder",
+                "-",
+                "Coal, Ore",
+                "Geode",
+                "Omni Geode",
+                "Geode",
+                "-",
+                "-"
+            ]
+        );
+        assert_eq!(
+            results::<HashedSeedGenerator>(&config, &state(123459074)),
+            [
+                "-",
+                "Ladder",
+                "Coal, Ore",
+                "Geode",
+                "-",
+                "Geode",
+                "-",
+                "-",
+                "-",
+                "-",
+                "-",
+                "-"
+            ]
+        );
+
+        config.excavator = true;
+        config.prospector = true;
+        config.monsters_cleared = true;
+        assert_eq!(
+            results::<HashedSeedGenerator>(&config, &state(123459074)),
+            [
+                "Ladder",
+                "Geode",
+                "Coal, Ore",
+                "Geode",
+                "-",
+                "Geode",
+                "-",
+                "-",
+                "-",
+                "Geode",
+                "-",
+                "-"
+            ]
+        );
+    }
+
+    #[test]
+    fn known_legacy_results() {
+        let mut config = config(45, 12, tiles(12));
+        assert_eq!(
+            results::<LegacySeedGenerator>(&config, &state(123457180)),
+            [
+                "-",
+                "-",
+                "-",
+                "-",
+                "Geode",
+                "Geode",
+                "-",
+                "Ladder",
+                "Coal, Ore",
+                "-",
+                "-",
+                "-"
+            ]
+        );
+
+        // The earlier ladder skips the ladder roll of later stones, shifting
+        // their drop rolls.
+        config.excavator = true;
+        config.prospector = true;
+        config.monsters_cleared = true;
+        assert_eq!(
+            results::<LegacySeedGenerator>(&config, &state(123457180)),
+            [
+                "-",
+                "Ladder",
+                "-",
+                "-",
+                "-",
+                "Omni Geode",
+                "Coal, Ore",
+                "Geode",
+                "Coal, Ore",
+                "-",
+                "-",
+                "-"
+            ]
+        );
+    }
+
+    #[test]
+    fn coal_drops_with_ore() {
+        let config = config(45, 200, tiles(200));
+        for game_id in 0..50 {
+            let breaks = PlainStones::<HashedSeedGenerator>::new(&config)
+                .unwrap()
+                .break_stones(&state(game_id))
+                .unwrap();
+            for stone in breaks {
+                if stone.drops.contains(&StoneDrop::Coal) {
+                    assert!(stone.drops.contains(&StoneDrop::Ore));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ladder_roll_skipped_after_ladder() {
+        let config = config(7, 10, tiles(10));
+        let state = state(uninfested_game(7));
+        let breaks = PlainStones::<HashedSeedGenerator>::new(&config)
+            .unwrap()
+            .break_stones(&state)
+            .unwrap();
+
+        // The last stone spawns the ladder if no other stone did.
+        let ladder = ladder_stone(&breaks).unwrap() as usize;
+        assert_eq!(breaks.iter().filter(|stone| stone.ladder).count(), 1);
+        for (i, stone) in breaks.iter().enumerate() {
+            let rolled = i + 1 < ladder || (i + 1 == ladder && ladder < breaks.len());
+            assert_eq!(stone.ladder_chance.is_some(), rolled, "stone {}", i + 1);
+        }
+        assert_eq!(breaks.last().unwrap().ladder_chance, None);
+    }
+
+    #[test]
+    fn no_ladder_on_bottom_floor() {
+        let config = config(MAX_MINE_LEVEL, 10, tiles(10));
+        for game_id in 0..20 {
+            let breaks = PlainStones::<HashedSeedGenerator>::new(&config)
+                .unwrap()
+                .break_stones(&state(game_id))
+                .unwrap();
+            assert!(breaks
+                .iter()
+                .all(|stone| !stone.ladder && stone.ladder_chance.is_none()));
+        }
+    }
+
+    #[test]
+    fn no_ladder_on_infested_floor() {
+        let level = 13;
+        let game_id = (0..)
+            .find(|game_id| {
+                predict_floor_type::<HashedSeedGenerator>(&state(*game_id), level)
+                    != FloorType::Normal
+            })
+            .unwrap();
+        let config = config(level, 10, tiles(10));
+        let breaks = PlainStones::<HashedSeedGenerator>::new(&config)
+            .unwrap()
+            .break_stones(&state(game_id))
+            .unwrap();
+        assert!(breaks
+            .iter()
+            .all(|stone| !stone.ladder && stone.ladder_chance.is_none()));
+    }
+
+    #[test]
+    fn rejects_out_of_range_level() {
+        let mut config = config(1, 1, tiles(1));
+        config.level = None;
+        let stones = PlainStones::<HashedSeedGenerator>::new(&config).unwrap();
+        let state = PredictionGameState {
+            deepest_mine_level: u32::MAX,
+            ..Default::default()
+        };
+        assert!(stones.break_stones(&state).is_err());
+
+        let state = PredictionGameState {
+            deepest_mine_level: MAX_MINE_LEVEL,
+            ..Default::default()
+        };
+        assert!(stones.break_stones(&state).is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_tile() {
+        let config = config(1, 1, vec![MineTile { x: i32::MAX, y: 0 }]);
+        let stones = PlainStones::<HashedSeedGenerator>::new(&config).unwrap();
+        assert!(stones.break_stones(&state(0)).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use std::{cmp::Ordering, cmp::Reverse, collections::BinaryHeap};
+
+/// A seed and its score.
+///
+/// Ordered by score with lower seeds winning ties so ranked results are
+/// deterministic.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ScoredSeed {
+    pub score: f64,
+    pub seed: i32,
+}
+
+impl PartialEq for ScoredSeed {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ScoredSeed {}
+
+impl PartialOrd for ScoredSeed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScoredSeed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| other.seed.cmp(&self.seed))
+    }
+}
+
+/// Keeps the `max` highest scoring seeds pushed into it.
+#[derive(Debug)]
+pub(crate) struct TopSeeds {
+    max: usize,
+    // Min-heap so the worst seed is always on top ready to be replaced.
+    heap: BinaryHeap<Reverse<ScoredSeed>>,
+}
+
+impl TopSeeds {
+    pub fn new(max: usize) -> Self {
+        Self {
+            max,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, scored: ScoredSeed) {
+        if self.heap.len() < self.max {
+            self.heap.push(Reverse(scored));
+        } else if let Some(mut worst) = self.heap.peek_mut() {
+            if scored > worst.0 {
+                *worst = Reverse(scored);
+            }
+        }
+    }
+
+    pub fn merge(mut self, other: Self) -> Self {
+        for Reverse(scored) in other.heap {
+            self.push(scored);
+        }
+        self
+    }
+
+    /// Returns the kept seeds, highest score first.
+    pub fn into_sorted_vec(self) -> Vec<ScoredSeed> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(scored)| scored)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn top(max: usize, scores: &[(i32, f64)]) -> TopSeeds {
+        let mut top = TopSeeds::new(max);
+        for &(seed, score) in scores {
+            top.push(ScoredSeed { score, seed });
+        }
+        top
+    }
+
+    fn seeds(top: TopSeeds) -> Vec<i32> {
+        top.into_sorted_vec()
+            .into_iter()
+            .map(|scored| scored.seed)
+            .collect()
+    }
+
+    #[test]
+    fn keeps_highest_scores_best_first() {
+        let top = top(3, &[(1, 0.2), (2, 0.9), (3, 0.5), (4, 1.0), (5, 0.1)]);
+        assert_eq!(seeds(top), vec![4, 2, 3]);
+    }
+
+    #[test]
+    fn ties_prefer_lower_seeds() {
+        let top = top(2, &[(30, 0.5), (10, 0.5), (20, 0.5)]);
+        assert_eq!(seeds(top), vec![10, 20]);
+    }
+
+    #[test]
+    fn merge_keeps_highest_scores() {
+        let a = top(3, &[(1, 0.2), (2, 0.9), (3, 0.5)]);
+        let b = top(3, &[(4, 1.0), (5, 0.1), (6, 0.5)]);
+        assert_eq!(seeds(a.merge(b)), vec![4, 2, 3]);
+
+        // Merging is independent of how the seeds were split.
+        let a = top(3, &[(4, 1.0)]);
+        let b = top(3, &[(1, 0.2), (2, 0.9), (3, 0.5), (5, 0.1), (6, 0.5)]);
+        assert_eq!(seeds(b.merge(a)), vec![4, 2, 3]);
+    }
+
+    #[test]
+    fn empty_when_max_is_zero() {
+        assert!(seeds(top(0, &[(1, 1.0)])).is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+use schemars::{json_schema, schema_for, Schema, SchemaGenerator};
+use sdv::predictor::{geode::GeodeType, night_event::NightEvent};
+use serde::de::DeserializeOwned;
+
+use crate::SeedFinderConfig;
+
+/// Generate a JSON Schema describing [`SeedFinderConfig`].
+///
+/// Covers [`PredictorConfig`](crate::PredictorConfig) and every predictor
+/// config.  Editors like VS Code can use it for autocomplete and validation
+/// by referencing it from a config's `$schema` field.
+pub fn config_schema() -> serde_json::Value {
+    schema_for!(SeedFinderConfig).to_value()
+}
+
+/// Schema of a string enum defined in `sdv`.
+///
+/// `sdv` types don't implement [`JsonSchema`](schemars::JsonSchema) so their
+/// values are listed here.  Names that `T` no longer accepts are left out so
+/// the schema never allows values that fail to parse.
+fn sdv_enum_schema<T: DeserializeOwned>(names: &[&str]) -> Schema {
+    let names = names
+        .iter()
+        .filter(|name| serde_json::from_value::<T>((**name).into()).is_ok())
+        .collect::<Vec<_>>();
+    json_schema!({
+        "type": "string",
+        "enum": names,
+    })
+}
+
+pub(crate) fn geode_type_schema(_generator: &mut SchemaGenerator) -> Schema {
+    sdv_enum_schema::<GeodeType>(&[
+        "geode",
+        "frozen_geode",
+        "magma_geode",
+        "omni_geode",
+        "artifact_trove",
+        "golden_coconut",
+        "mystery_box",
+        "golden_mystery_box",
+    ])
+}
+
+pub(crate) fn night_event_schema(_generator: &mut SchemaGenerator) -> Schema {
+    sdv_enum_schema::<NightEvent>(&[
+        "none",
+        "fairy",
+        "witch",
+        "meteorite",
+        "stone_owl",
+        "strange_capsule",
+        "earthquake",
+    ])
+}
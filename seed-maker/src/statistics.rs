@@ -0,0 +1,169 @@
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use anyhow::Context;
+use rayon::prelude::*;
+use sdv::{predictor::PredictionGameState, GameData, Locale};
+use serde::{Deserialize, Serialize};
+
+use crate::{CancelHandle, Predictor, Result};
+
+/// Aggregate outcomes of every predictor across a seed range.
+///
+/// Produced by [`SeedFinder::statistics`](crate::SeedFinder::statistics).
+///
+/// ## Example JSON
+/// ```text
+/// {
+///    "seeds_scanned": 1000000,
+///    "matches": 4211,
+///    "predictors": [
+///        {
+///            "buckets": [
+///                {
+///                    "outcome": "Fairy",
+///                    "count": 9870
+///                },
+///                {
+///                    "outcome": "None",
+///                    "count": 990130
+///                }
+///            ]
+///        }
+///    ]
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Statistics {
+    /// Number of seeds scanned.
+    ///
+    /// Less than the size of the seed range if the scan was cancelled.
+    pub seeds_scanned: u64,
+
+    /// Number of scanned seeds that matched every predictor.
+    pub matches: u64,
+
+    /// Outcome histograms for each predictor, in configuration order.
+    pub predictors: Vec<Histogram>,
+}
+
+/// Histogram of a single predictor's outcomes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Histogram {
+    /// Number of seeds with each outcome, most common first.
+    pub buckets: Vec<Bucket>,
+}
+
+/// A single bucket of a [`Histogram`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Bucket {
+    /// Outcome as described by [`Predictor::outcome`].
+    pub outcome: String,
+
+    /// Number of seeds with this outcome.
+    pub count: u64,
+}
+
+/// Running totals of a statistics scan.
+struct Totals {
+    seeds_scanned: u64,
+    matches: u64,
+    outcomes: Vec<HashMap<String, u64>>,
+}
+
+impl Totals {
+    fn new(predictors: usize) -> Self {
+        Self {
+            seeds_scanned: 0,
+            matches: 0,
+            outcomes: vec![HashMap::new(); predictors],
+        }
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        self.seeds_scanned += other.seeds_scanned;
+        self.matches += other.matches;
+        for (totals, other) in self.outcomes.iter_mut().zip(other.outcomes) {
+            for (outcome, count) in other {
+                *totals.entry(outcome).or_default() += count;
+            }
+        }
+        self
+    }
+}
+
+impl Statistics {
+    /// Tally the outcome of every predictor for every seed in `seed_range`.
+    ///
+    /// `progress` is called with the number of seeds scanned so far each time
+    /// roughly a thousandth of the range has been scanned.
+    pub(crate) fn measure(
+        predictors: &[Box<dyn Predictor>],
+        game_data: &GameData,
+        locale: &Locale,
+        initial_state: &PredictionGameState,
+        seed_range: &RangeInclusive<i32>,
+        cancel: &CancelHandle,
+        progress: &(dyn Fn(u64) + Sync),
+    ) -> Result<Self> {
+        let range_start = *seed_range.start() as i64;
+        let seed_count = (*seed_range.end() as i64 - range_start) as u64 + 1;
+        let step_size = (seed_count / 1000).max(1);
+        let seeds_scanned = AtomicU64::new(0);
+        let totals = seed_range
+            .clone()
+            .into_par_iter()
+            .take_any_while(|_| !cancel.is_cancelled())
+            .try_fold(
+                || Totals::new(predictors.len()),
+                |mut totals, seed| {
+                    let state = PredictionGameState {
+                        game_id: seed as u32,
+                        ..*initial_state
+                    };
+                    let mut matched = true;
+                    for (i, predictor) in predictors.iter().enumerate() {
+                        let context = || format!("predictors[{i}] failed on seed {seed}");
+                        let (predicted, outcome) = predictor
+                            .outcome(game_data, locale, &state)
+                            .with_context(context)?;
+                        matched &= predicted;
+                        *totals.outcomes[i].entry(outcome).or_default() += 1;
+                    }
+                    totals.seeds_scanned += 1;
+                    totals.matches += matched as u64;
+                    // Counting every seed in a shared counter is too slow so
+                    // progress is reported in steps, like seed searches.
+                    if ((seed as i64 - range_start) as u64).is_multiple_of(step_size) {
+                        let scanned =
+                            seeds_scanned.fetch_add(step_size, Ordering::Relaxed) + step_size;
+                        progress(scanned.min(seed_count));
+                    }
+                    Ok::<_, anyhow::Error>(totals)
+                },
+            )
+            .try_reduce(|| Totals::new(predictors.len()), |a, b| Ok(a.merge(b)))?;
+
+        let histograms = totals
+            .outcomes
+            .into_iter()
+            .map(|outcomes| {
+                let mut buckets = outcomes
+                    .into_iter()
+                    .map(|(outcome, count)| Bucket { outcome, count })
+                    .collect::<Vec<_>>();
+                buckets.sort_by(|a, b| b.count.cmp(&a.count).then(a.outcome.cmp(&b.outcome)));
+                Histogram { buckets }
+            })
+            .collect();
+
+        Ok(Self {
+            seeds_scanned: totals.seeds_scanned,
+            matches: totals.matches,
+            predictors: histograms,
+        })
+    }
+}
@@ -0,0 +1,476 @@
+use std::fmt;
+
+use sdv::{
+    common::ItemId,
+    predictor::{
+        self,
+        geode::{predict_single_geode, GeodeType},
+        PredictionGameState,
+    },
+    rng::{HashedSeedGenerator, LegacySeedGenerator, SeedGenerator},
+    GameData, Locale,
+};
+
+use crate::{
+    geode_plan::plan_end,
+    item_names::resolve_item,
+    mine_level::{possible_floor_types, MAX_MINE_LEVEL},
+    plain_stones::MAX_TILE,
+    Date, DayRangeConfig, GarbageConfig, GeodeConfig, GeodePlanConfig, GeodeRangeConfig,
+    GeodeSequenceConfig, MineLevelConfig, PlainStonesConfig, PredictorConfig, Result, RngType,
+    SeedFinderConfig,
+};
+
+/// Number of geodes cracked when checking that a geode can drop an item.
+const GEODE_SAMPLES: u32 = 10_000;
+
+/// A problem found by [`SeedFinderConfig::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON path of the offending field, like `predictors[1].child.items[0]`.
+    pub path: String,
+
+    /// Description of the problem.
+    pub message: String,
+
+    /// True if the problem is suspicious but doesn't stop the config from
+    /// being used.
+    pub warning: bool,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.warning {
+            write!(f, "{}: warning: {}", self.path, self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Collects [`ValidationError`]s while walking a config.
+struct Validator<'a> {
+    game_data: &'a GameData,
+    locale: &'a Locale,
+    initial_state: PredictionGameState,
+    rng_type: &'a RngType,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator<'_> {
+    fn error(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.errors.push(ValidationError {
+            path: path.into(),
+            message: message.into(),
+            warning: false,
+        });
+    }
+
+    fn warning(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.errors.push(ValidationError {
+            path: path.into(),
+            message: message.into(),
+            warning: true,
+        });
+    }
+
+    fn item(&mut self, path: String, name: &str) -> Option<ItemId> {
+        match resolve_item(self.game_data, self.locale, name) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                self.error(path, e.to_string());
+                None
+            }
+        }
+    }
+
+    fn predictors(&mut self, path: &str, configs: &[PredictorConfig]) {
+        for (i, config) in configs.iter().enumerate() {
+            self.predictor(&format!("{path}[{i}]"), config);
+        }
+    }
+
+    /// Check the child predictors of an [`AllOf`](crate::AllOf) or
+    /// [`AnyOf`](crate::AnyOf).
+    ///
+    /// An empty `AllOf` would match every seed and an empty `AnyOf` none so
+    /// both need at least one child.
+    fn branches(&mut self, path: &str, configs: &[PredictorConfig]) {
+        if configs.is_empty() {
+            self.error(
+                format!("{path}.predictors"),
+                "must contain at least one predictor",
+            );
+        }
+        self.predictors(&format!("{path}.predictors"), configs)
+    }
+
+    fn predictor(&mut self, path: &str, config: &PredictorConfig) {
+        match config {
+            PredictorConfig::AllOf(config) => self.branches(path, &config.predictors),
+            PredictorConfig::AnyOf(config) => self.branches(path, &config.predictors),
+            PredictorConfig::DayRange(config) => self.day_range(path, config),
+            PredictorConfig::Garbage(config) => self.garbage(path, config),
+            PredictorConfig::Geode(config) => self.geode(path, config),
+            PredictorConfig::GeodePlan(config) => self.geode_plan(path, config),
+            PredictorConfig::GeodeRange(config) => self.geode_range(path, config),
+            PredictorConfig::GeodeSequence(config) => self.geode_sequence(path, config),
+            PredictorConfig::MineLevel(config) => self.mine_level(path, config),
+            PredictorConfig::NightEvent(_) | PredictorConfig::Weather(_) => (),
+            PredictorConfig::Not(config) => self.predictor(&format!("{path}.child"), &config.child),
+            PredictorConfig::PlainStones(config) => self.plain_stones(path, config),
+        }
+    }
+
+    fn day_range(&mut self, path: &str, config: &DayRangeConfig) {
+        let start_day = config.start_day.days_played();
+        let end_day = config.end_day.days_played();
+        if start_day > end_day {
+            self.error(
+                format!("{path}.end_day"),
+                format!(
+                    "{} is before start_day {}",
+                    Date::from_days_played(end_day),
+                    Date::from_days_played(start_day)
+                ),
+            );
+        } else {
+            let days = (end_day - start_day + 1) as usize;
+            if config.min_matches > days {
+                self.error(
+                    format!("{path}.min_matches"),
+                    format!(
+                        "{} is more than the {days} days between start_day and end_day",
+                        config.min_matches
+                    ),
+                );
+            }
+        }
+        self.predictor(&format!("{path}.child"), &config.child);
+    }
+
+    fn garbage(&mut self, path: &str, config: &GarbageConfig) {
+        for (i, name) in config.items.iter().enumerate() {
+            self.item(format!("{path}.items[{i}]"), name);
+        }
+    }
+
+    fn mine_level(&mut self, path: &str, config: &MineLevelConfig) {
+        for (field, level) in [
+            ("start_level", config.start_level),
+            ("end_level", config.end_level),
+        ] {
+            if !(1..=MAX_MINE_LEVEL).contains(&level) {
+                self.error(
+                    format!("{path}.{field}"),
+                    format!("{level} is not a mine floor between 1 and {MAX_MINE_LEVEL}"),
+                );
+            }
+        }
+        if config.start_level > config.end_level {
+            self.error(
+                format!("{path}.end_level"),
+                format!(
+                    "{} is before start_level {}",
+                    config.end_level, config.start_level
+                ),
+            );
+        }
+        for (i, floor) in config.floors.iter().enumerate() {
+            if !(1..=MAX_MINE_LEVEL).contains(&floor.level) {
+                self.error(
+                    format!("{path}.floors[{i}].level"),
+                    format!(
+                        "{} is not a mine floor between 1 and {MAX_MINE_LEVEL}",
+                        floor.level
+                    ),
+                );
+            } else if !possible_floor_types(floor.level).contains(&floor.floor_type) {
+                self.error(
+                    format!("{path}.floors[{i}].floor_type"),
+                    format!(
+                        "floor {} is never a {} floor",
+                        floor.level,
+                        floor.floor_type.to_string().to_lowercase()
+                    ),
+                );
+            }
+        }
+    }
+
+    fn plain_stones(&mut self, path: &str, config: &PlainStonesConfig) {
+        match config.level {
+            Some(level) => {
+                if !(1..=MAX_MINE_LEVEL).contains(&level) {
+                    self.error(
+                        format!("{path}.level"),
+                        format!("{level} is not a mine floor between 1 and {MAX_MINE_LEVEL}"),
+                    );
+                }
+            }
+            None => {
+                let deepest = self.initial_state.deepest_mine_level;
+                if deepest >= MAX_MINE_LEVEL {
+                    self.error(
+                        format!("{path}.level"),
+                        format!(
+                            "must be set when game_state.deepest_mine_level {deepest} is the bottom of the mines"
+                        ),
+                    );
+                }
+            }
+        }
+        if config.stones.is_empty() {
+            self.error(format!("{path}.stones"), "must contain at least one stone");
+        }
+        for (i, tile) in config.stones.iter().enumerate() {
+            for (axis, value) in [("x", tile.x), ("y", tile.y)] {
+                if !(0..=MAX_TILE).contains(&value) {
+                    self.error(
+                        format!("{path}.stones[{i}].{axis}"),
+                        format!("{value} is not a tile between 0 and {MAX_TILE}"),
+                    );
+                }
+            }
+        }
+        if (config.stones_on_level as usize) < config.stones.len() {
+            self.error(
+                format!("{path}.stones_on_level"),
+                format!(
+                    "{} is fewer than the {} stones broken",
+                    config.stones_on_level,
+                    config.stones.len()
+                ),
+            );
+        }
+        if let Some(within) = config.ladder_within {
+            if within == 0 || within as usize > config.stones.len() {
+                self.error(
+                    format!("{path}.ladder_within"),
+                    format!(
+                        "must be between 1 and the {} stones broken",
+                        config.stones.len()
+                    ),
+                );
+            }
+        }
+    }
+
+    fn geode(&mut self, path: &str, config: &GeodeConfig) {
+        let item = self.item(format!("{path}.item"), &config.item);
+        let Some(geode) = self.sdv_geode(path, config.geode_type) else {
+            return;
+        };
+        if let Some(item) = item {
+            self.geode_drop(path, &geode, config.geode_type, "item", &config.item, &item);
+        }
+    }
+
+    fn geode_plan(&mut self, path: &str, config: &GeodePlanConfig) {
+        if config.geodes.is_empty() {
+            self.error(format!("{path}.geodes"), "must contain at least one geode");
+        }
+        if plan_end(config).is_none() {
+            self.error(
+                format!("{path}.geodes"),
+                format!(
+                    "{} geodes starting at start_geodes_cracked {} overflows geodes_cracked",
+                    config.geodes.len(),
+                    config.start_geodes_cracked
+                ),
+            );
+        }
+        for (i, slot) in config.geodes.iter().enumerate() {
+            let path = format!("{path}.geodes[{i}]");
+            let item = slot
+                .item
+                .as_ref()
+                .map(|name| (name, self.item(format!("{path}.item"), name)));
+            let Some(geode) = self.sdv_geode(&path, slot.geode_type) else {
+                continue;
+            };
+            if let Some((name, Some(item))) = item {
+                self.geode_drop(&path, &geode, slot.geode_type, "item", name, &item);
+            }
+        }
+    }
+
+    fn geode_range(&mut self, path: &str, config: &GeodeRangeConfig) {
+        let start = config.start_geodes_cracked;
+        let end = config.end_geodes_cracked;
+        if start > end {
+            self.error(
+                format!("{path}.end_geodes_cracked"),
+                format!("{end} is before start_geodes_cracked {start}"),
+            );
+        } else {
+            let geodes = (end - start + 1) as usize;
+            if config.min_matches > geodes {
+                self.error(
+                    format!("{path}.min_matches"),
+                    format!(
+                        "{} is more than the {geodes} geodes between start_geodes_cracked and end_geodes_cracked",
+                        config.min_matches
+                    ),
+                );
+            }
+        }
+        self.geode(&format!("{path}.child"), &config.child);
+    }
+
+    fn geode_sequence(&mut self, path: &str, config: &GeodeSequenceConfig) {
+        if config.count == 0 {
+            self.error(format!("{path}.count"), "must be greater than 0");
+        }
+        if config
+            .start_geodes_cracked
+            .checked_add(config.count)
+            .is_none()
+        {
+            self.error(
+                format!("{path}.count"),
+                format!(
+                    "{} geodes starting at start_geodes_cracked {} overflows geodes_cracked",
+                    config.count, config.start_geodes_cracked
+                ),
+            );
+        }
+        let items = config
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| self.item(format!("{path}.items[{i}].item"), &item.item))
+            .collect::<Vec<_>>();
+        for (i, item) in config.items.iter().enumerate() {
+            if let Some(position) = item.position {
+                if position == 0 || position > config.count {
+                    self.error(
+                        format!("{path}.items[{i}].position"),
+                        format!(
+                            "{position} is outside the sequence of {} geodes",
+                            config.count
+                        ),
+                    );
+                }
+            }
+        }
+
+        let Some(geode) = self.sdv_geode(path, config.geode_type) else {
+            return;
+        };
+        for (i, (config_item, item)) in config.items.iter().zip(items).enumerate() {
+            if let Some(item) = item {
+                let field = format!("items[{i}].item");
+                self.geode_drop(
+                    path,
+                    &geode,
+                    config.geode_type,
+                    &field,
+                    &config_item.item,
+                    &item,
+                );
+            }
+        }
+    }
+
+    /// Create the `sdv` geode for `geode_type`, recording an error at
+    /// `{path}.geode_type` if it can't be.
+    fn sdv_geode(&mut self, path: &str, geode_type: GeodeType) -> Option<predictor::geode::Geode> {
+        match predictor::geode::Geode::new(geode_type, self.game_data) {
+            Ok(geode) => Some(geode),
+            Err(e) => {
+                self.error(format!("{path}.geode_type"), e.to_string());
+                None
+            }
+        }
+    }
+
+    /// Check that `geode` can drop `item`, recording a warning at
+    /// `{path}.{field}` if it wasn't seen in any sampled geode.
+    ///
+    /// Sampling can miss rare drops so this is only a warning.
+    fn geode_drop(
+        &mut self,
+        path: &str,
+        geode: &predictor::geode::Geode,
+        geode_type: GeodeType,
+        field: &str,
+        name: &str,
+        item: &ItemId,
+    ) {
+        let can_drop = match self.rng_type {
+            RngType::Hashed => {
+                geode_can_drop::<HashedSeedGenerator>(geode, &self.initial_state, item)
+            }
+            RngType::Legacy => {
+                geode_can_drop::<LegacySeedGenerator>(geode, &self.initial_state, item)
+            }
+        };
+        match can_drop {
+            Ok(true) => (),
+            Ok(false) => self.warning(
+                format!("{path}.{field}"),
+                format!(
+                    "\"{name}\" was never dropped by {geode_type:?} in {GEODE_SAMPLES} sampled geodes"
+                ),
+            ),
+            Err(e) => self.error(format!("{path}.geode_type"), e.to_string()),
+        }
+    }
+}
+
+/// Returns true if `geode` drops `item` when cracked in any of a sample of
+/// games.
+///
+/// The game doesn't expose drop tables so this is checked by cracking
+/// geodes in [`GEODE_SAMPLES`] different games.
+fn geode_can_drop<G: SeedGenerator>(
+    geode: &predictor::geode::Geode,
+    initial_state: &PredictionGameState,
+    item: &ItemId,
+) -> Result<bool> {
+    for i in 0..GEODE_SAMPLES {
+        let state = PredictionGameState {
+            game_id: i,
+            geodes_cracked: i % 100 + 1,
+            ..*initial_state
+        };
+        if predict_single_geode::<G>(geode, &state)?.item == *item {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Check `config` for problems that deserializing can't catch.
+///
+/// See [`SeedFinderConfig::validate`].
+pub(crate) fn validate(
+    config: &SeedFinderConfig,
+    game_data: &GameData,
+    locale: &Locale,
+) -> Vec<ValidationError> {
+    let mut validator = Validator {
+        game_data,
+        locale,
+        initial_state: config.game_state.clone().into(),
+        rng_type: &config.rng_type,
+        errors: Vec::new(),
+    };
+
+    if config.max_seeds == 0 {
+        validator.error("max_seeds", "must be greater than 0");
+    }
+    if !config.full_range && config.seed_start > config.seed_end {
+        validator.error(
+            "seed_end",
+            format!(
+                "{} is less than seed_start {}",
+                config.seed_end, config.seed_start
+            ),
+        );
+    }
+    validator.predictors("predictors", &config.predictors);
+
+    validator.errors
+}
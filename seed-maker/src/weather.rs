@@ -1,6 +1,7 @@
 use std::{fmt::Debug, marker::PhantomData};
 
 use anyhow::anyhow;
+use schemars::JsonSchema;
 use sdv::{
     predictor::weather::{predict_weather, WeatherLocation},
     rng::SeedGenerator,
@@ -8,7 +9,7 @@ use sdv::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::{Predictor, Result};
+use crate::{Predictor, PredictorReport, Result};
 
 /// Configuration for [`Weather`].
 ///
@@ -19,7 +20,7 @@ use crate::{Predictor, Result};
 ///     "is_rain": true
 /// }
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema, Serialize)]
 pub struct WeatherConfig {
     /// Set to true to require rain.
     ///
@@ -40,6 +41,56 @@ pub struct WeatherConfig {
     pub maybe_storm: bool,
 }
 
+/// Structured report of a [`Weather`] predictor.
+///
+/// Each field is the chance, between 0.0 and 1.0, of that weather.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WeatherReport {
+    /// Chance of sun.
+    pub sun: f64,
+
+    /// Chance of rain.
+    pub rain: f64,
+
+    /// Chance of wind.
+    pub wind: f64,
+
+    /// Chance of a storm.
+    pub storm: f64,
+
+    /// Chance of snow.
+    pub snow: f64,
+
+    /// Chance of a festival.
+    pub festival: f64,
+
+    /// Chance of green rain.
+    pub green_rain: f64,
+}
+
+/// Describe each kind of weather with a non-zero chance.
+fn describe_weather(weather: &sdv::predictor::weather::Weather) -> String {
+    let chances = [
+        (weather.sun, "Sun"),
+        (weather.rain, "Rain"),
+        (weather.wind, "Wind"),
+        (weather.storm, "Storm"),
+        (weather.snow, "Snow"),
+        (weather.fesival, "Festival"),
+        (weather.green_rain, "Green Rain"),
+    ]
+    .iter()
+    .filter_map(|(chance, name)| {
+        if *chance > 0.0 {
+            Some(format!("{:2.1}% {}", chance * 100.0, name))
+        } else {
+            None
+        }
+    })
+    .collect::<Vec<_>>();
+    chances.join(", ")
+}
+
 /// Predictor for a day's weather.
 pub struct Weather<G: Send + Sync + SeedGenerator> {
     is_rain: bool,
@@ -67,6 +118,15 @@ impl<G: Send + Sync + SeedGenerator> Weather<G> {
     }
 }
 
+impl<G: Send + Sync + SeedGenerator> Weather<G> {
+    /// Returns true if `weather` has every configured kind of weather.
+    fn is_match(&self, weather: &sdv::predictor::weather::Weather) -> bool {
+        (!self.is_rain || (weather.rain + weather.storm) >= 1.0)
+            && (!self.is_storm || weather.storm >= 1.0)
+            && (!self.maybe_storm || weather.storm >= 0.0)
+    }
+}
+
 impl<G: Send + Sync + SeedGenerator> Debug for Weather<G> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Weather")
@@ -81,9 +141,7 @@ impl<G: Send + Sync + SeedGenerator> Debug for Weather<G> {
 impl<G: Send + Sync + SeedGenerator> Predictor for Weather<G> {
     fn predict(&self, state: &sdv::predictor::PredictionGameState) -> Result<bool> {
         let weather = predict_weather::<G>(&self.location, state);
-        Ok((!self.is_rain || (weather.rain + weather.storm) >= 1.0)
-            && (!self.is_storm || weather.storm >= 1.0)
-            && (!self.maybe_storm || weather.storm >= 0.0))
+        Ok(self.is_match(&weather))
     }
 
     fn report(
@@ -94,26 +152,37 @@ impl<G: Send + Sync + SeedGenerator> Predictor for Weather<G> {
         writer: &mut dyn std::io::prelude::Write,
     ) -> Result<()> {
         let weather = predict_weather::<G>(&self.location, state);
-        let chances = [
-            (weather.sun, "Sun"),
-            (weather.rain, "Rain"),
-            (weather.wind, "Wind"),
-            (weather.storm, "Storm"),
-            (weather.snow, "Snow"),
-            (weather.fesival, "Festival"),
-            (weather.green_rain, "Green Rain"),
-        ]
-        .iter()
-        .filter_map(|(chance, name)| {
-            if *chance > 0.0 {
-                Some(format!("{:2.1}% {}", chance * 100.0, name))
-            } else {
-                None
-            }
-        })
-        .collect::<Vec<_>>();
-        writeln!(writer, "Weather: {}", chances.join(", "))?;
+        writeln!(writer, "Weather: {}", describe_weather(&weather))?;
 
         Ok(())
     }
+
+    fn report_structured(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &sdv::predictor::PredictionGameState,
+    ) -> Result<PredictorReport> {
+        let weather = predict_weather::<G>(&self.location, state);
+        Ok(PredictorReport::Weather(WeatherReport {
+            sun: weather.sun,
+            rain: weather.rain,
+            wind: weather.wind,
+            storm: weather.storm,
+            snow: weather.snow,
+            festival: weather.fesival,
+            green_rain: weather.green_rain,
+        }))
+    }
+
+    /// Buckets seeds by the chance of each kind of weather.
+    fn outcome(
+        &self,
+        _game_data: &GameData,
+        _locale: &Locale,
+        state: &sdv::predictor::PredictionGameState,
+    ) -> Result<(bool, String)> {
+        let weather = predict_weather::<G>(&self.location, state);
+        Ok((self.is_match(&weather), describe_weather(&weather)))
+    }
 }